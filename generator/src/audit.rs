@@ -0,0 +1,306 @@
+//! Checking a factory that already exists against what it should be able to do.
+//!
+//! Give it how many machines are running each recipe and it works out how fast each line
+//! actually runs, which ones are starved for ingredients, which have more machines than
+//! anything downstream can use, and which items are holding everything else back.
+//!
+//! Anything nothing in the factory makes is assumed to come in from outside as fast as it's
+//! needed, and anything nothing in the factory uses is assumed to leave it.
+//!
+//! The machine counts have to be typed in, see `parse_machines`. Nothing reads them out of a save.
+
+use crate::{
+    calculator::{net_output, Calculator},
+    items::Item,
+    names::resolve,
+    producers::Producer,
+    recipes::Recipe,
+};
+
+use anyhow::anyhow;
+use enum_map::EnumMap;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use std::fmt::{self, Display, Formatter};
+
+/// Anything smaller than this is just floating-point noise.
+const EPSILON: f64 = 1e-9;
+
+/// How the whole factory's doing.
+#[derive(Debug, Clone, Serialize)]
+pub struct Audit {
+    /// One line per recipe with any machines, in the order of `Recipe`.
+    pub lines: Vec<AuditLine>,
+    /// Items there isn't enough of, worst first.
+    pub bottlenecks: Vec<Bottleneck>,
+}
+
+/// How one recipe's machines are doing.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct AuditLine {
+    pub recipe: Recipe,
+    pub made_in: Producer,
+    pub machines: u32,
+    /// Crafts per minute if every machine ran flat out.
+    pub capacity: f64,
+    /// Crafts per minute it actually manages.
+    pub crafts_per_minute: f64,
+    /// `crafts_per_minute / capacity`.
+    pub utilization: f64,
+    /// The ingredient there isn't enough of, if it's starved.
+    pub starved_on: Option<Item>,
+    /// How many machines it'd take to keep up with everything downstream.
+    ///
+    /// This is `None` if some of what it makes leaves the factory, since then
+    /// there's no such thing as too much.
+    pub machines_needed: Option<f64>,
+}
+
+/// An item the factory doesn't make enough of.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Bottleneck {
+    pub item: Item,
+    /// How much more per minute the machines using it want.
+    pub shortfall: f64,
+    /// The recipe making it.
+    pub recipe: Recipe,
+    /// How many more of that recipe's machines it'd take to cover the shortfall.
+    pub extra_machines: f64,
+}
+
+impl AuditLine {
+    /// How many machines could be taken away without slowing anything down.
+    pub fn spare_machines(&self) -> u32 {
+        match self.machines_needed {
+            Some(needed) => self
+                .machines
+                .saturating_sub((needed - EPSILON).ceil().max(0.0) as u32),
+            None => 0,
+        }
+    }
+}
+
+/// Work out how the factory with `machines` running each recipe is doing.
+///
+/// The calculator's speeds and mining setup are used for how fast each machine is.
+///
+/// When a line is short on more than one thing, the machines sharing those things
+/// with it get a little less than they could; this errs on the side of finding problems.
+pub fn audit(calculator: &Calculator, machines: &EnumMap<Recipe, u32>) -> Audit {
+    let info = calculator.info();
+    let recipes: Vec<_> = machines
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .map(|(recipe, _)| recipe)
+        .collect();
+    let capacity: EnumMap<Recipe, f64> = EnumMap::from(|recipe| {
+        machines[recipe] as f64 * calculator.crafts_per_machine(&info.recipes.0[recipe])
+    });
+
+    let mut made_here = EnumMap::from(|_| false);
+    let mut used_here = EnumMap::from(|_| false);
+    for &recipe in recipes.iter() {
+        let entry = &info.recipes.0[recipe];
+        for stack in entry.results.iter() {
+            made_here[stack.item] = true;
+        }
+        for stack in entry.ingredients.iter() {
+            used_here[stack.item] = true;
+        }
+    }
+
+    // Start everything at full speed and slow down whatever can't get enough.
+    // Rates only ever go down, so this always settles.
+    let mut rates = capacity;
+    let mut starved_on = EnumMap::from(|_| None);
+    loop {
+        let (supply, demand) = flows(calculator, &recipes, &rates);
+        let mut changed = false;
+        for &recipe in recipes.iter() {
+            let mut ratio = 1.0;
+            let mut short = None;
+            for stack in info.recipes.0[recipe].ingredients.iter() {
+                let item = stack.item;
+                if made_here[item] && demand[item] > EPSILON {
+                    let available = supply[item] / demand[item];
+                    if available < ratio {
+                        ratio = available;
+                        short = Some(item);
+                    }
+                }
+            }
+            let rate = capacity[recipe] * ratio;
+            if rate < rates[recipe] - EPSILON {
+                rates[recipe] = rate;
+                starved_on[recipe] = short;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let (supply, demand) = flows(calculator, &recipes, &rates);
+    // What everything would use if nothing was starved
+    let (_, wanted) = flows(calculator, &recipes, &capacity);
+    let lines = recipes
+        .iter()
+        .map(|&recipe| {
+            let entry = &info.recipes.0[recipe];
+            let per_machine = calculator.crafts_per_machine(entry);
+            // Lines where everything they make gets used only run as fast as it's taken away
+            let internal = entry.results.iter().all(|stack| used_here[stack.item]);
+            let share_of = |used: &EnumMap<Item, f64>| {
+                entry
+                    .results
+                    .iter()
+                    .map(|stack| rates[recipe] * used[stack.item] / supply[stack.item].max(EPSILON))
+                    .fold(0.0, f64::max)
+            };
+            let crafts_per_minute = if internal {
+                rates[recipe].min(share_of(&demand))
+            } else {
+                rates[recipe]
+            };
+            AuditLine {
+                recipe,
+                made_in: entry.made_in,
+                machines: machines[recipe],
+                capacity: capacity[recipe],
+                crafts_per_minute,
+                utilization: crafts_per_minute / capacity[recipe],
+                starved_on: starved_on[recipe],
+                machines_needed: if internal {
+                    Some(share_of(&wanted) / per_machine)
+                } else {
+                    None
+                },
+            }
+        })
+        .collect();
+
+    // Only blame items whose own machines have everything they need,
+    // since otherwise it's really whatever those are short on
+    let mut bottlenecks: Vec<_> = Item::iter()
+        .filter(|&item| made_here[item])
+        .filter_map(|item| {
+            let shortfall = wanted[item] - supply[item];
+            let starving = recipes
+                .iter()
+                .any(|&recipe| starved_on[recipe] == Some(item));
+            if !starving || shortfall <= EPSILON {
+                return None;
+            }
+            let makers: Vec<_> = recipes
+                .iter()
+                .copied()
+                .filter(|&recipe| net_output(&info.recipes.0[recipe], item) > EPSILON)
+                .collect();
+            if makers.iter().any(|&recipe| starved_on[recipe].is_some()) {
+                return None;
+            }
+            let recipe = *makers.first()?;
+            let entry = &info.recipes.0[recipe];
+            Some(Bottleneck {
+                item,
+                shortfall,
+                recipe,
+                extra_machines: shortfall
+                    / (calculator.crafts_per_machine(entry) * net_output(entry, item)),
+            })
+        })
+        .collect();
+    bottlenecks.sort_by(|a, b| b.shortfall.total_cmp(&a.shortfall));
+
+    Audit { lines, bottlenecks }
+}
+
+/// How much of each item is made and used per minute with the recipes running at `rates`.
+fn flows(
+    calculator: &Calculator,
+    recipes: &[Recipe],
+    rates: &EnumMap<Recipe, f64>,
+) -> (EnumMap<Item, f64>, EnumMap<Item, f64>) {
+    let mut supply = EnumMap::from(|_| 0.0);
+    let mut demand = EnumMap::from(|_| 0.0);
+    for &recipe in recipes {
+        let entry = &calculator.info().recipes.0[recipe];
+        for stack in entry.results.iter() {
+            supply[stack.item] += stack.count * rates[recipe];
+        }
+        for stack in entry.ingredients.iter() {
+            demand[stack.item] += stack.count * rates[recipe];
+        }
+    }
+    (supply, demand)
+}
+
+/// Read machine counts typed in by hand, like `iron smelting: 12, gear: 4`.
+///
+/// Entries are separated by commas or newlines, and recipe names can be anything
+/// `names::resolve` understands.
+pub fn parse_machines(text: &str) -> anyhow::Result<EnumMap<Recipe, u32>> {
+    let mut machines = EnumMap::from(|_| 0);
+    for entry in text.split([',', '\n']) {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let (name, count) = entry
+            .rsplit_once(':')
+            .ok_or_else(|| anyhow!("`{}` should look like `recipe: count`", entry))?;
+        let recipe: Recipe = resolve(name)?;
+        machines[recipe] += count
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| anyhow!("`{}` isn't a number of machines", count.trim()))?;
+    }
+    Ok(machines)
+}
+
+impl Display for Audit {
+    /// A table with a row for each recipe.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<36} {:>8} {:>8} {:>10} {:>6}  Notes",
+            "Recipe", "Machines", "Needed", "Crafts/min", "Use"
+        )?;
+        for line in self.lines.iter() {
+            let needed = match line.machines_needed {
+                Some(needed) => format!("{:.2}", needed),
+                None => "-".to_string(),
+            };
+            let mut notes = Vec::new();
+            if let Some(item) = line.starved_on {
+                notes.push(format!("starved on {}", item));
+            }
+            if line.spare_machines() > 0 {
+                notes.push(format!("{} spare", line.spare_machines()));
+            }
+            if self.bottlenecks.iter().any(|b| b.recipe == line.recipe) {
+                notes.push("bottleneck".to_string());
+            }
+            writeln!(
+                f,
+                "{:<36} {:>8} {:>8} {:>10.2} {:>5.0}%  {}",
+                line.recipe.to_string(),
+                line.machines,
+                needed,
+                line.crafts_per_minute,
+                line.utilization * 100.0,
+                notes.join(", ")
+            )?;
+        }
+        for b in self.bottlenecks.iter() {
+            writeln!(
+                f,
+                "Short {:.2} {}/min; {:.2} more {} machines would cover it",
+                b.shortfall, b.item, b.extra_machines, b.recipe
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use generator::{generator::AllDSPInfo, server};
+
+use anyhow::anyhow;
+use tiny_http::Server;
+
+fn main() -> anyhow::Result<()> {
+    // Localhost unless told otherwise, so nothing outside can get at it
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:8000".to_string());
+
+    println!("Generating DSP info...");
+    let info = AllDSPInfo::generate();
+
+    let server = Server::http(&addr).map_err(|e| anyhow!("couldn't start server: {}", e))?;
+    println!("Listening on http://{}", addr);
+    server::serve(&info, &server);
+    Ok(())
+}
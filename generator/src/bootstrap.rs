@@ -0,0 +1,228 @@
+use crate::{
+    calculator::{net_output, Calculator, ProductionPlan},
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    producers::Producer,
+    recipes::Recipe,
+    technologies::Technology,
+    upgrades::MATRICES,
+};
+
+use anyhow::bail;
+use enum_map::EnumMap;
+use strum::IntoEnumIterator;
+
+/// Plans a fresh playthrough from nothing up to some technology.
+pub struct BootstrapPlanner<'a> {
+    info: &'a AllDSPInfo,
+    /// How many of each matrix to automate per minute.
+    pub matrices_per_minute: f64,
+}
+
+/// Everything it takes to get to the target.
+#[derive(Debug)]
+pub struct Bootstrap {
+    pub target: Technology,
+    /// One stage per technology researched, in an order that always has the prereqs first.
+    pub stages: Vec<BootstrapStage>,
+}
+
+/// Researching one technology.
+#[derive(Debug)]
+pub struct BootstrapStage {
+    pub tech: Technology,
+    /// The matrices this tech probably needs, which have to be automated by now.
+    ///
+    /// This is an estimate, since we don't have the real research costs:
+    /// see `BootstrapPlanner::estimated_matrices_for`.
+    pub estimated_matrices: Vec<Item>,
+    /// Recipes this tech unlocks once it's done.
+    pub unlocks: Vec<Recipe>,
+    /// The fewest buildings it takes to make the matrices, using only what's been unlocked.
+    pub buildings: EnumMap<Item, u32>,
+    /// Buildings to handcraft on top of the ones from the last stage.
+    ///
+    /// Feed these to a `Replicator` to find out what that takes.
+    pub handcraft: EnumMap<Item, u32>,
+    /// Producers the matrices need that nothing unlocked can act as yet,
+    /// so Icarus has to do their part by hand.
+    pub by_hand: Vec<Producer>,
+    /// How the matrices get made.
+    pub production: ProductionPlan,
+}
+
+impl<'a> BootstrapPlanner<'a> {
+    pub fn new(info: &'a AllDSPInfo) -> Self {
+        Self {
+            info,
+            matrices_per_minute: 10.0,
+        }
+    }
+
+    /// Work out every stage from `DysonSphereProgram` up to and including `target`.
+    ///
+    /// This also researches the techs for any matrices the stages need. It fails if
+    /// some techs can never be ready, like when a tech needs a matrix that's only
+    /// unlocked after it.
+    pub fn plan(&self, target: Technology) -> anyhow::Result<Bootstrap> {
+        let mut researched = EnumMap::from(|_| false);
+        researched[Technology::DysonSphereProgram] = true;
+        self.plan_from(target, &researched, &EnumMap::from(|_| 0))
+    }
+
+    /// Like `plan`, but starting from a game where the techs in `researched` are already done
+    /// and the buildings in `built` are already up.
+    pub fn plan_from(
+        &self,
+        target: Technology,
+        researched: &EnumMap<Technology, bool>,
+        built: &EnumMap<Item, u32>,
+    ) -> anyhow::Result<Bootstrap> {
+        let mut needed = EnumMap::from(|_| false);
+        let mut todo = vec![target];
+        while let Some(tech) = todo.pop() {
+            if needed[tech] || researched[tech] {
+                continue;
+            }
+            needed[tech] = true;
+            todo.extend_from_slice(tech.prerequisites());
+            todo.extend(
+                self.estimated_matrices_for(tech)
+                    .into_iter()
+                    .map(|matrix| self.matrix_tech(matrix)),
+            );
+        }
+        needed[Technology::DysonSphereProgram] = false;
+        let mut pending: Vec<_> = Technology::iter().filter(|&tech| needed[tech]).collect();
+        pending.sort_by_key(|tech| tech.depth());
+
+        let mut researched = *researched;
+        researched[Technology::DysonSphereProgram] = true;
+        let mut built = *built;
+        let mut stages = Vec::new();
+        while !pending.is_empty() {
+            // The shallowest tech whose prereqs and matrices are all done.
+            // Depth alone isn't enough, since the matrix techs can be as deep as what needs them
+            let ready = pending.iter().position(|&tech| {
+                tech.prerequisites()
+                    .iter()
+                    .all(|&prereq| researched[prereq])
+                    && self
+                        .estimated_matrices_for(tech)
+                        .into_iter()
+                        .all(|matrix| researched[self.matrix_tech(matrix)])
+            });
+            let ready = match ready {
+                Some(ready) => ready,
+                None => bail!("These techs can never be researched: {:?}", pending),
+            };
+            let tech = pending.remove(ready);
+            let stage = self.stage(tech, &researched, &built);
+            for (item, &count) in stage.buildings.iter() {
+                built[item] = built[item].max(count);
+            }
+            researched[tech] = true;
+            stages.push(stage);
+        }
+
+        Ok(Bootstrap { target, stages })
+    }
+
+    /// Estimate which matrices it takes to research the tech.
+    ///
+    /// This is a rule of thumb, not the game's real costs, which we don't have.
+    /// The techs right after `DysonSphereProgram` get researched with handcrafted items.
+    /// Everything after that needs electromagnetic matrices, plus every other matrix
+    /// that's been unlocked on the way there. The tech that unlocks a matrix doesn't need it.
+    pub fn estimated_matrices_for(&self, tech: Technology) -> Vec<Item> {
+        if tech.depth() <= 1 {
+            return Vec::new();
+        }
+        let prereqs = tech.all_prerequisites();
+        MATRICES
+            .iter()
+            .copied()
+            .filter(|&matrix| {
+                let unlocker = self.matrix_tech(matrix);
+                unlocker != tech
+                    && (matrix == Item::ElectromagneticMatrix || prereqs.contains(&unlocker))
+            })
+            .collect()
+    }
+
+    /// The tech that unlocks making the matrix.
+    fn matrix_tech(&self, matrix: Item) -> Technology {
+        let recipe = self.info.production_methods.0[matrix][0];
+        self.info.recipes.0[recipe].unlocked_by
+    }
+
+    /// Plan one stage, knowing what's been researched and built before it.
+    fn stage(
+        &self,
+        tech: Technology,
+        researched: &EnumMap<Technology, bool>,
+        built: &EnumMap<Item, u32>,
+    ) -> BootstrapStage {
+        let unlocked = |recipe: Recipe| researched[self.info.recipes.0[recipe].unlocked_by];
+
+        let estimated_matrices = self.estimated_matrices_for(tech);
+
+        let mut calculator = Calculator::new(self.info);
+        for item in Item::iter() {
+            calculator.recipe_choices[item] = self.info.production_methods.0[item]
+                .iter()
+                .copied()
+                .find(|&recipe| {
+                    unlocked(recipe) && net_output(&self.info.recipes.0[recipe], item) > 0.0
+                });
+            calculator.raw_items[item] = calculator.recipe_choices[item].is_none();
+        }
+
+        // Use the first building of each kind that can be made yet
+        let mut building_for: EnumMap<Producer, Option<Item>> = EnumMap::from(|_| None);
+        for (producer, building) in building_for.iter_mut() {
+            *building = producer.buildings().into_iter().find(|&item| {
+                self.info.production_methods.0[item]
+                    .iter()
+                    .any(|&recipe| unlocked(recipe) && self.info.recipes.0[recipe].handcraftable)
+            });
+            if let Some(item) = *building {
+                calculator.speeds[producer] = item.building().unwrap().speed;
+            }
+        }
+
+        let targets: Vec<_> = estimated_matrices
+            .iter()
+            .map(|&item| ItemStack {
+                item,
+                count: self.matrices_per_minute,
+            })
+            .collect();
+        let production = calculator.plan(&targets);
+
+        let mut buildings = EnumMap::from(|_| 0);
+        let mut by_hand = Vec::new();
+        for line in production.lines.iter() {
+            match building_for[line.made_in] {
+                Some(item) => buildings[item] += line.machines.ceil() as u32,
+                None if !by_hand.contains(&line.made_in) => by_hand.push(line.made_in),
+                None => {}
+            }
+        }
+        let handcraft = EnumMap::from(|item| buildings[item] - buildings[item].min(built[item]));
+
+        let unlocks = Recipe::iter()
+            .filter(|&recipe| self.info.recipes.0[recipe].unlocked_by == tech)
+            .collect();
+
+        BootstrapStage {
+            tech,
+            estimated_matrices,
+            unlocks,
+            buildings,
+            handcraft,
+            by_hand,
+            production,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    calculator::{output_bonus, ProductionPlan},
+    generator::AllDSPInfo,
+    items::ItemStack,
+    producers::Producer,
+    recipes::Recipe,
+};
+
+use enum_map::EnumMap;
+use serde::Serialize;
+
+use std::fmt::Write;
+
+/// A list of what to build for a plan, grouped by the kind of machine.
+///
+/// This doesn't say anything about where to put things, just what they are.
+#[derive(Debug, Serialize)]
+pub struct BuildOrder {
+    pub groups: Vec<BuildGroup>,
+    /// Items per minute that have to be brought in from somewhere else.
+    pub imports: Vec<ItemStack>,
+}
+
+/// All the lines made in one kind of producer.
+#[derive(Debug, Serialize)]
+pub struct BuildGroup {
+    pub producer: Producer,
+    pub lines: Vec<BuildLine>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BuildLine {
+    pub recipe: Recipe,
+    /// How many machines to actually build.
+    pub machines: u32,
+    /// How many machines it would take if you could build fractions of them.
+    pub exact_machines: f64,
+    /// Items per minute going into the whole line.
+    pub inputs: Vec<ItemStack>,
+    /// Items per minute coming out of the whole line.
+    pub outputs: Vec<ItemStack>,
+}
+
+impl BuildOrder {
+    pub fn new(info: &AllDSPInfo, plan: &ProductionPlan) -> Self {
+        let mut groups: EnumMap<Producer, Vec<BuildLine>> = EnumMap::from(|_| Vec::new());
+        for line in plan.lines.iter() {
+            let entry = &info.recipes.0[line.recipe];
+            let scale = |stacks: &[ItemStack], bonus: f64| {
+                stacks
+                    .iter()
+                    .filter(|stack| stack.count > 0.0)
+                    .map(|stack| ItemStack {
+                        item: stack.item,
+                        count: stack.count * bonus * line.crafts_per_minute,
+                    })
+                    .collect()
+            };
+            groups[line.made_in].push(BuildLine {
+                recipe: line.recipe,
+                machines: line.machines.ceil() as u32,
+                exact_machines: line.machines,
+                inputs: scale(&entry.ingredients, 1.0),
+                outputs: scale(&entry.results, output_bonus(entry, plan.extra_products)),
+            });
+        }
+
+        Self {
+            groups: groups
+                .into_iter()
+                .filter(|(_, lines)| !lines.is_empty())
+                .map(|(producer, lines)| BuildGroup { producer, lines })
+                .collect(),
+            imports: plan
+                .imports
+                .iter()
+                .filter(|(_, &count)| count > 0.0)
+                .map(|(item, &count)| ItemStack { item, count })
+                .collect(),
+        }
+    }
+
+    /// One row per line, with inputs and outputs written like `IronOre:60;CopperOre:30`.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("producer,recipe,machines,exact_machines,inputs,outputs\n");
+        for group in self.groups.iter() {
+            for line in group.lines.iter() {
+                // Writing to a String can't fail
+                writeln!(
+                    out,
+                    "{:?},{:?},{},{},{},{}",
+                    group.producer,
+                    line.recipe,
+                    line.machines,
+                    line.exact_machines,
+                    csv_stacks(&line.inputs),
+                    csv_stacks(&line.outputs),
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+
+    /// A table per producer, plus a list of imports at the bottom.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        for group in self.groups.iter() {
+            writeln!(out, "## {:?}\n", group.producer).unwrap();
+            writeln!(
+                out,
+                "| Recipe | Machines | Inputs (/min) | Outputs (/min) |"
+            )
+            .unwrap();
+            writeln!(out, "| --- | --- | --- | --- |").unwrap();
+            for line in group.lines.iter() {
+                writeln!(
+                    out,
+                    "| {:?} | {} ({:.2}) | {} | {} |",
+                    line.recipe,
+                    line.machines,
+                    line.exact_machines,
+                    markdown_stacks(&line.inputs),
+                    markdown_stacks(&line.outputs),
+                )
+                .unwrap();
+            }
+            out.push('\n');
+        }
+
+        if !self.imports.is_empty() {
+            writeln!(out, "## Imports\n").unwrap();
+            for stack in self.imports.iter() {
+                writeln!(out, "- {:?}: {:.2}/min", stack.item, stack.count).unwrap();
+            }
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+fn csv_stacks(stacks: &[ItemStack]) -> String {
+    stacks
+        .iter()
+        .map(|stack| format!("{:?}:{}", stack.item, stack.count))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn markdown_stacks(stacks: &[ItemStack]) -> String {
+    stacks
+        .iter()
+        .map(|stack| format!("{:.2}x {:?}", stack.count, stack.item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,175 @@
+use crate::{
+    generator::AllDSPInfo, items::Item, logistics::station_slots, producers::Producer,
+    recipes::Recipe,
+};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+/// What a building does with power.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub enum PowerRole {
+    Consumer,
+    Generator,
+    /// Holds onto power for later, or moves it around in items.
+    Storage,
+    /// Moves power from one place to another.
+    Transmission,
+    /// Doesn't need power at all.
+    Unpowered,
+}
+
+/// Something you can put into a building besides its ingredients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub enum Slot {
+    /// Burns fuel to make power.
+    Fuel,
+    /// Takes Graviton Lenses to boost receiving.
+    GravitonLens,
+    Drones(u32),
+    Vessels(u32),
+    /// Takes Space Warpers for the vessels.
+    Warpers,
+}
+
+/// Everything about a building that isn't a recipe.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Building {
+    pub item: Item,
+    /// What this acts as when it's placed, if it makes things.
+    pub producer: Option<Producer>,
+    /// Roughly how many tiles it takes up, as `[width, length]`.
+    pub size: [u32; 2],
+    pub power: PowerRole,
+    pub slots: Vec<Slot>,
+    /// How fast this works compared to a normal producer of its kind.
+    ///
+    /// This is 1.0 for everything except the assembling machines.
+    pub speed: f64,
+}
+
+/// How to get a building and what it does.
+#[derive(Debug)]
+pub struct BuildingSummary<'a> {
+    pub building: Building,
+    /// All the recipes that make the building.
+    pub made_by: &'a [Recipe],
+    /// All the recipes the building can run.
+    pub runs: Vec<Recipe>,
+}
+
+impl Item {
+    /// Get the building this item places, or `None` if it isn't a building.
+    pub fn building(self) -> Option<Building> {
+        use PowerRole::*;
+
+        let (producer, size, power, speed) = match self {
+            Item::TeslaTower => (None, [1, 1], Transmission, 1.0),
+            Item::WirelessPowerTower => (None, [1, 1], Transmission, 1.0),
+            Item::SatelliteSubstation => (None, [5, 5], Transmission, 1.0),
+            Item::WindTurbine => (None, [3, 3], Generator, 1.0),
+            Item::ThermalPowerStation => (None, [5, 5], Generator, 1.0),
+            Item::SolarPanel => (None, [3, 3], Generator, 1.0),
+            Item::MiniFusionPowerStation => (None, [5, 5], Generator, 1.0),
+            Item::ArtificialStar => (None, [7, 7], Generator, 1.0),
+            Item::Accumulator => (None, [3, 3], Storage, 1.0),
+            Item::FullAccumulator => (None, [3, 3], Storage, 1.0),
+            Item::EnergyExchanger => (Some(Producer::EnergyExchanger), [5, 5], Storage, 1.0),
+            Item::RayReceiver => (Some(Producer::RayReceiver), [5, 5], Generator, 1.0),
+            Item::ConveyorMK1 | Item::ConveyorMK2 | Item::ConveyorMK3 => {
+                (None, [1, 1], Unpowered, 1.0)
+            }
+            Item::Splitter => (None, [1, 1], Unpowered, 1.0),
+            Item::StorageMK1 | Item::StorageMK2 => (None, [3, 3], Unpowered, 1.0),
+            Item::StorageTank => (None, [3, 3], Unpowered, 1.0),
+            Item::SorterMK1 | Item::SorterMK2 | Item::SorterMK3 => (None, [1, 1], Consumer, 1.0),
+            Item::PlanetaryLogisticsStation => (None, [8, 8], Consumer, 1.0),
+            Item::InterstellarLogisticsStation => (None, [8, 8], Consumer, 1.0),
+            Item::OrbitCollector => (Some(Producer::OrbitCollector), [8, 8], Unpowered, 1.0),
+            Item::EMRailEjector => (None, [5, 5], Consumer, 1.0),
+            Item::VerticalLaunchingSilo => (None, [9, 9], Consumer, 1.0),
+            Item::MiningMachine => (Some(Producer::MiningMachine), [3, 3], Consumer, 1.0),
+            Item::OilExtractor => (Some(Producer::OilExtractor), [3, 3], Consumer, 1.0),
+            Item::WaterPump => (Some(Producer::WaterPump), [3, 3], Consumer, 1.0),
+            Item::OilRefinery => (Some(Producer::OilRefinery), [5, 3], Consumer, 1.0),
+            Item::ChemicalPlant => (Some(Producer::ChemicalPlant), [5, 3], Consumer, 1.0),
+            Item::Fractionator => (Some(Producer::Fractionator), [3, 3], Consumer, 1.0),
+            Item::MiniatureParticleCollider => (
+                Some(Producer::MiniatureParticleCollider),
+                [9, 5],
+                Consumer,
+                1.0,
+            ),
+            Item::MatrixLab => (Some(Producer::MatrixLab), [3, 3], Consumer, 1.0),
+            Item::Smelter => (Some(Producer::Smelter), [3, 3], Consumer, 1.0),
+            Item::AssemblingMachineMK1 => {
+                (Some(Producer::AssemblingMachine), [3, 3], Consumer, 0.75)
+            }
+            Item::AssemblingMachineMK2 => {
+                (Some(Producer::AssemblingMachine), [3, 3], Consumer, 1.0)
+            }
+            Item::AssemblingMachineMK3 => {
+                (Some(Producer::AssemblingMachine), [3, 3], Consumer, 1.5)
+            }
+            _ => return None,
+        };
+
+        let slots = match self {
+            Item::ThermalPowerStation | Item::MiniFusionPowerStation | Item::ArtificialStar => {
+                vec![Slot::Fuel]
+            }
+            Item::RayReceiver => vec![Slot::GravitonLens],
+            Item::PlanetaryLogisticsStation | Item::InterstellarLogisticsStation => {
+                let (drones, vessels) = station_slots(self).unwrap();
+                let mut slots = vec![Slot::Drones(drones)];
+                if vessels > 0 {
+                    slots.push(Slot::Vessels(vessels));
+                    slots.push(Slot::Warpers);
+                }
+                slots
+            }
+            _ => Vec::new(),
+        };
+
+        Some(Building {
+            item: self,
+            producer,
+            size,
+            power,
+            slots,
+            speed,
+        })
+    }
+}
+
+impl Producer {
+    /// All the buildings that act as this producer.
+    pub fn buildings(self) -> Vec<Item> {
+        Item::iter()
+            .filter(|item| item.building().and_then(|b| b.producer) == Some(self))
+            .collect()
+    }
+}
+
+/// Find out how to make a building and what it does,
+/// or `None` if the item isn't a building.
+pub fn describe(info: &AllDSPInfo, item: Item) -> Option<BuildingSummary<'_>> {
+    let building = item.building()?;
+    let runs = match building.producer {
+        Some(producer) => info
+            .recipes
+            .0
+            .iter()
+            .filter(|(_, entry)| entry.made_in == producer)
+            .map(|(recipe, _)| recipe)
+            .collect(),
+        None => Vec::new(),
+    };
+    Some(BuildingSummary {
+        building,
+        made_by: &info.production_methods.0[item],
+        runs,
+    })
+}
@@ -0,0 +1,281 @@
+use crate::{
+    generator::{AllDSPInfo, RecipeEntry},
+    items::{Item, ItemStack},
+    mining::{MiningModel, MiningRequirement},
+    producers::Producer,
+    recipes::Recipe,
+};
+
+use enum_map::EnumMap;
+use serde::Serialize;
+
+/// Anything smaller than this is just floating-point noise.
+const EPSILON: f64 = 1e-9;
+
+/// Works out how many of each recipe it takes to make some items at some rate.
+#[derive(Clone)]
+pub struct Calculator<'a> {
+    info: &'a AllDSPInfo,
+    /// Use this recipe to make the item instead of the first one in `production_methods`.
+    ///
+    /// Choices that don't end up making any of the item are ignored.
+    pub recipe_choices: EnumMap<Item, Option<Recipe>>,
+    /// Don't try to make these items, just say they need to be brought in.
+    pub raw_items: EnumMap<Item, bool>,
+    /// How fast each kind of producer works.
+    ///
+    /// These are all 1.0 by default, so assembling machines are assumed to be MK.II.
+    pub speeds: EnumMap<Producer, f64>,
+    /// How long each recipe takes compared to the data, for trying out what-ifs.
+    pub time_scale: EnumMap<Recipe, f64>,
+    /// Extra products from proliferated ingredients, as a fraction, like 0.25 for +25%.
+    ///
+    /// Recipes with no ingredients, like mining, don't get any. The proliferator itself
+    /// isn't in the data yet, so what it costs to spray isn't counted.
+    pub extra_products: f64,
+    /// How mining machines and oil extractors are set up.
+    pub mining: MiningModel,
+}
+
+/// Everything that goes into making the targets.
+#[derive(Debug, Serialize)]
+pub struct ProductionPlan {
+    /// What was asked for. The counts are items per minute.
+    pub targets: Vec<ItemStack>,
+    /// One line per recipe used, in the order of `Recipe`.
+    pub lines: Vec<PlanLine>,
+    /// Items per minute that have to come from outside the plan.
+    pub imports: EnumMap<Item, f64>,
+    /// Byproducts per minute that nothing in the plan used up.
+    pub surplus: EnumMap<Item, f64>,
+    /// How many miners and veins the mining lines need.
+    pub mining: Vec<MiningRequirement>,
+    /// The calculator's `extra_products`, so anything adding up what the lines make
+    /// can count the bonus too. See `output_bonus`.
+    pub extra_products: f64,
+}
+
+/// A bunch of machines all running the same recipe.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PlanLine {
+    pub recipe: Recipe,
+    pub made_in: Producer,
+    /// How many times the recipe finishes per minute, across all machines.
+    pub crafts_per_minute: f64,
+    /// This is fractional; round it up to get something you can build.
+    pub machines: f64,
+}
+
+impl<'a> Calculator<'a> {
+    pub fn new(info: &'a AllDSPInfo) -> Self {
+        Self {
+            info,
+            recipe_choices: EnumMap::from(|_| None),
+            raw_items: EnumMap::from(|_| false),
+            speeds: EnumMap::from(|_| 1.0),
+            time_scale: EnumMap::from(|_| 1.0),
+            extra_products: 0.0,
+            mining: MiningModel::default(),
+        }
+    }
+
+    /// The data this calculator works off of.
+    pub fn info(&self) -> &'a AllDSPInfo {
+        self.info
+    }
+
+    /// Get the recipe this calculator will use to make the item, if any.
+    pub fn recipe_for(&self, item: Item) -> Option<Recipe> {
+        if self.raw_items[item] {
+            return None;
+        }
+        let makes_some =
+            |&recipe: &Recipe| net_output(&self.info.recipes.0[recipe], item) > EPSILON;
+        self.recipe_choices[item].filter(makes_some).or_else(|| {
+            self.info.production_methods.0[item]
+                .iter()
+                .copied()
+                .find(makes_some)
+        })
+    }
+
+    /// Figure out how to make all the targets. Each target's count is in items per minute.
+    ///
+    /// Byproducts get used up by anything that needs them later on, so the order
+    /// of the targets can change the result slightly.
+    pub fn plan(&self, targets: &[ItemStack]) -> ProductionPlan {
+        let mut crafts = EnumMap::from(|_| 0.0);
+        let mut imports = EnumMap::from(|_| 0.0);
+        let mut surplus = EnumMap::from(|_| 0.0);
+        for target in targets {
+            self.demand(
+                target.item,
+                target.count,
+                &mut crafts,
+                &mut imports,
+                &mut surplus,
+                &mut Vec::new(),
+            );
+        }
+
+        let mut lines = Vec::new();
+        let mut mining = Vec::new();
+        for (recipe, &crafts_per_minute) in crafts.iter() {
+            if crafts_per_minute <= EPSILON {
+                continue;
+            }
+            let entry = &self.info.recipes.0[recipe];
+            let machines = match self.mining.requirement(entry, crafts_per_minute) {
+                Some(req) => {
+                    let machines = req.machines;
+                    mining.push(req);
+                    machines
+                }
+                None => self.machines_for(entry, crafts_per_minute),
+            };
+            lines.push(PlanLine {
+                recipe,
+                made_in: entry.made_in,
+                crafts_per_minute,
+                machines,
+            });
+        }
+
+        ProductionPlan {
+            targets: targets.to_vec(),
+            lines,
+            imports,
+            surplus,
+            mining,
+            extra_products: self.extra_products,
+        }
+    }
+
+    /// How many machines it takes to run the recipe this many times a minute.
+    pub fn machines_for(&self, entry: &RecipeEntry, crafts_per_minute: f64) -> f64 {
+        crafts_per_minute * entry.time as f64 * self.time_scale[entry.recipe]
+            / 60.0
+            / self.speeds[entry.made_in]
+    }
+
+    /// How many times a minute one machine can run the recipe, counting mining setups too.
+    pub fn crafts_per_machine(&self, entry: &RecipeEntry) -> f64 {
+        let machines = match self.mining.requirement(entry, 1.0) {
+            Some(req) => req.machines,
+            None => self.machines_for(entry, 1.0),
+        };
+        1.0 / machines
+    }
+
+    /// Add `amount` per minute of the item to the plan.
+    ///
+    /// `making` is all the items we're in the middle of figuring out, so loops
+    /// get imported instead of going forever.
+    fn demand(
+        &self,
+        item: Item,
+        mut amount: f64,
+        crafts: &mut EnumMap<Recipe, f64>,
+        imports: &mut EnumMap<Item, f64>,
+        surplus: &mut EnumMap<Item, f64>,
+        making: &mut Vec<Item>,
+    ) {
+        let leftover = amount.min(surplus[item]);
+        surplus[item] -= leftover;
+        amount -= leftover;
+        if amount <= EPSILON {
+            return;
+        }
+
+        let recipe = match self.recipe_for(item) {
+            Some(recipe) if !making.contains(&item) => recipe,
+            _ => {
+                imports[item] += amount;
+                return;
+            }
+        };
+        let entry = &self.info.recipes.0[recipe];
+        let bonus = output_bonus(entry, self.extra_products);
+        let made = net_output(entry, item)
+            + (bonus - 1.0)
+                * entry
+                    .results
+                    .iter()
+                    .filter(|stack| stack.item == item)
+                    .map(|stack| stack.count)
+                    .sum::<f64>();
+        let count = amount / made;
+        crafts[recipe] += count;
+
+        for result in entry.results.iter() {
+            if result.item != item {
+                surplus[result.item] += result.count * bonus * count;
+            }
+        }
+        making.push(item);
+        for ingredient in entry.ingredients.iter() {
+            if ingredient.item != item {
+                self.demand(
+                    ingredient.item,
+                    ingredient.count * count,
+                    crafts,
+                    imports,
+                    surplus,
+                    making,
+                );
+            }
+        }
+        making.pop();
+    }
+}
+
+impl ProductionPlan {
+    /// Get the line for the given recipe, if it's in the plan.
+    pub fn line(&self, recipe: Recipe) -> Option<&PlanLine> {
+        self.lines.iter().find(|line| line.recipe == recipe)
+    }
+
+    /// Everything per minute that has to come from outside the plan or out of the ground.
+    ///
+    /// Anything that comes out of a recipe with no ingredients, like mining, counts.
+    pub fn raw_inputs(&self, info: &AllDSPInfo) -> EnumMap<Item, f64> {
+        let mut raw = self.imports;
+        for line in self.lines.iter() {
+            let entry = &info.recipes.0[line.recipe];
+            if entry.ingredients.is_empty() {
+                for result in entry.results.iter() {
+                    raw[result.item] += result.count * line.crafts_per_minute;
+                }
+            }
+        }
+        raw
+    }
+}
+
+/// What to multiply the recipe's results by, with `extra_products` from proliferating
+/// its ingredients. Recipes with no ingredients don't get any.
+pub fn output_bonus(entry: &RecipeEntry, extra_products: f64) -> f64 {
+    if entry.ingredients.is_empty() {
+        1.0
+    } else {
+        1.0 + extra_products
+    }
+}
+
+/// How many of the item one craft of the recipe makes, after taking out any
+/// of it the recipe uses up.
+pub fn net_output(entry: &RecipeEntry, item: Item) -> f64 {
+    let made: f64 = entry
+        .results
+        .iter()
+        .filter(|stack| stack.item == item)
+        .map(|stack| stack.count)
+        .sum();
+    let used: f64 = entry
+        .ingredients
+        .iter()
+        .filter(|stack| stack.item == item)
+        .map(|stack| stack.count)
+        .sum();
+    made - used
+}
@@ -0,0 +1,148 @@
+use crate::{
+    calculator::{Calculator, ProductionPlan},
+    items::{Item, ItemStack},
+};
+
+use anyhow::ensure;
+
+/// Works out what it takes to get some amount of power out of a star.
+///
+/// The defaults are the game's numbers for a star with no research.
+#[derive(Debug, Clone, Copy)]
+pub struct DysonPlanner {
+    /// How bright the star is. The sun-like starting star is 1.0.
+    pub luminosity: f64,
+    /// How much power a single sail or shell cell makes around a star with 1.0 luminosity, in watts.
+    pub sail_power: f64,
+    /// How long a sail lasts in the swarm before it burns up, in seconds.
+    pub sail_lifetime: f64,
+    /// How much of the power should come from the sphere instead of the swarm.
+    ///
+    /// 0.0 is swarm only, 1.0 is sphere only.
+    pub sphere_share: f64,
+    /// How many shell cells each rocket's worth of frame holds up.
+    pub cells_per_rocket: f64,
+    /// How long you're willing to wait for the sphere to finish, in minutes.
+    pub build_time: f64,
+    /// Sails an EM-Rail Ejector fires per minute.
+    pub ejector_rate: f64,
+    /// Rockets a Vertical Launching Silo fires per minute.
+    pub silo_rate: f64,
+    /// The most power a Ray Receiver can send to the grid, in watts.
+    pub receiver_power: f64,
+    /// How much received energy goes into one Critical Photon, in joules.
+    pub photon_energy: f64,
+}
+
+/// What it takes to hit the target power.
+#[derive(Debug)]
+pub struct DysonPlan {
+    /// The power asked for, in GW.
+    pub target_gw: f64,
+    /// How many sails need to be up in the swarm at any given time.
+    pub swarm_sails: f64,
+    /// How many shell cells the sphere needs.
+    pub sphere_cells: f64,
+    /// How many rockets go into the sphere's frame.
+    pub sphere_rockets: f64,
+    /// Sails launched per second, for both keeping the swarm up and filling the shells.
+    pub sails_per_second: f64,
+    /// Rockets launched per minute to finish the sphere in time.
+    pub rockets_per_minute: f64,
+    pub ejectors: f64,
+    pub silos: f64,
+    /// Ray Receivers needed if they all send power to the grid.
+    pub ray_receivers: f64,
+    /// Critical Photons made per minute if all the power goes into them instead.
+    pub critical_photons_per_minute: f64,
+    /// Everything it takes to make the sails and rockets.
+    pub production: ProductionPlan,
+}
+
+impl Default for DysonPlanner {
+    fn default() -> Self {
+        Self {
+            luminosity: 1.0,
+            sail_power: 36_000.0,
+            sail_lifetime: 5400.0,
+            sphere_share: 0.0,
+            cells_per_rocket: 2.0,
+            build_time: 60.0,
+            ejector_rate: 20.0,
+            silo_rate: 2.0,
+            receiver_power: 15_000_000.0,
+            photon_energy: 120_000_000.0,
+        }
+    }
+}
+
+impl DysonPlanner {
+    /// Power from one sail or shell cell around this star, in watts.
+    pub fn power_per_sail(&self) -> f64 {
+        self.sail_power * self.luminosity
+    }
+
+    /// Figure out how to get `target_gw` gigawatts out of the star,
+    /// and run the sails and rockets through the calculator.
+    ///
+    /// Fails if `sphere_share` isn't between 0 and 1, or anything that gets divided by is 0 or less.
+    pub fn plan(&self, calculator: &Calculator, target_gw: f64) -> anyhow::Result<DysonPlan> {
+        ensure!(
+            (0.0..=1.0).contains(&self.sphere_share),
+            "sphere_share should be between 0 and 1, not {}",
+            self.sphere_share
+        );
+        for (name, value) in [
+            ("luminosity", self.luminosity),
+            ("sail_power", self.sail_power),
+            ("sail_lifetime", self.sail_lifetime),
+            ("cells_per_rocket", self.cells_per_rocket),
+            ("build_time", self.build_time),
+            ("ejector_rate", self.ejector_rate),
+            ("silo_rate", self.silo_rate),
+            ("receiver_power", self.receiver_power),
+            ("photon_energy", self.photon_energy),
+        ] {
+            ensure!(value > 0.0, "{} should be more than 0, not {}", name, value);
+        }
+        ensure!(
+            target_gw >= 0.0,
+            "Can't plan for {} GW; it should be 0 or more",
+            target_gw
+        );
+
+        let target = target_gw * 1e9;
+        let swarm_sails = target * (1.0 - self.sphere_share) / self.power_per_sail();
+        let sphere_cells = target * self.sphere_share / self.power_per_sail();
+        let sphere_rockets = sphere_cells / self.cells_per_rocket;
+
+        let build_seconds = self.build_time * 60.0;
+        let sails_per_second = swarm_sails / self.sail_lifetime + sphere_cells / build_seconds;
+        let rockets_per_minute = sphere_rockets / self.build_time;
+
+        let production = calculator.plan(&[
+            ItemStack {
+                item: Item::SolarSail,
+                count: sails_per_second * 60.0,
+            },
+            ItemStack {
+                item: Item::SmallCarrierRocket,
+                count: rockets_per_minute,
+            },
+        ]);
+
+        Ok(DysonPlan {
+            target_gw,
+            swarm_sails,
+            sphere_cells,
+            sphere_rockets,
+            sails_per_second,
+            rockets_per_minute,
+            ejectors: sails_per_second * 60.0 / self.ejector_rate,
+            silos: rockets_per_minute / self.silo_rate,
+            ray_receivers: target / self.receiver_power,
+            critical_photons_per_minute: target / self.photon_energy * 60.0,
+            production,
+        })
+    }
+}
@@ -0,0 +1,210 @@
+//! Other ways to write out `AllDSPInfo`, for when the pretty JSON is too big.
+
+use crate::{generator::AllDSPInfo, schema};
+
+use anyhow::anyhow;
+use schemars::schema::{RootSchema, Schema, SchemaObject, SingleOrVec};
+use serde_json::Value;
+use strum_macros::EnumIter;
+
+/// All the ways we know how to write the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Format {
+    /// What `main.rs` writes to `dsp.json`.
+    PrettyJson,
+    CompactJson,
+    /// MessagePack, with field names kept.
+    ///
+    /// Enum maps come out as arrays in the order of their keys,
+    /// because that's what `enum_map` does for formats that aren't human-readable.
+    MessagePack,
+    /// CBOR, which comes out the same shape as `MessagePack`.
+    Cbor,
+    /// Compact JSON where every object is an array and every enum variant is a number.
+    ///
+    /// See `to_indexed`.
+    IndexedJson,
+    /// The indexed form, in MessagePack.
+    IndexedMessagePack,
+}
+
+impl Format {
+    /// What to put on the end of a file in this format.
+    ///
+    /// `main.rs` writes everything that isn't plain `json` to `dsp.<extension>`.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::PrettyJson | Format::CompactJson => "json",
+            Format::MessagePack => "msgpack",
+            Format::Cbor => "cbor",
+            Format::IndexedJson => "indexed.json",
+            Format::IndexedMessagePack => "indexed.msgpack",
+        }
+    }
+}
+
+/// Write the info in the given format.
+pub fn encode(info: &AllDSPInfo, format: Format) -> anyhow::Result<Vec<u8>> {
+    Ok(match format {
+        Format::PrettyJson => serde_json::to_vec_pretty(info)?,
+        Format::CompactJson => serde_json::to_vec(info)?,
+        Format::MessagePack => rmp_serde::to_vec_named(info)?,
+        Format::Cbor => {
+            let mut out = Vec::new();
+            ciborium::ser::into_writer(info, &mut out).map_err(|e| anyhow!("{}", e))?;
+            out
+        }
+        Format::IndexedJson => serde_json::to_vec(&to_indexed(info)?)?,
+        Format::IndexedMessagePack => rmp_serde::to_vec(&to_indexed(info)?)?,
+    })
+}
+
+/// How many bytes the info takes up in every format.
+pub fn size_report(info: &AllDSPInfo) -> anyhow::Result<Vec<(Format, usize)>> {
+    use strum::IntoEnumIterator;
+
+    Format::iter()
+        .map(|format| Ok((format, encode(info, format)?.len())))
+        .collect()
+}
+
+/// Turn the info into JSON with no names in it besides `names` itself, going off of the schema.
+///
+/// - Enum variants become their index in the schema's `enum` list, which is the order they're declared in.
+/// - Enum maps become arrays in the order of their key's variants.
+/// - Structs become arrays in the order of the schema's `properties`, which is alphabetical.
+///
+/// Anything reading it needs the schema for the same `schema_version` to make sense of it.
+pub fn to_indexed(info: &AllDSPInfo) -> anyhow::Result<Value> {
+    let root = schema::schema();
+    let value = serde_json::to_value(info)?;
+    Ok(index_object(&value, &root.schema, &root))
+}
+
+fn index(value: &Value, schema: &Schema, root: &RootSchema) -> Value {
+    match schema {
+        Schema::Object(schema) => index_object(value, schema, root),
+        // `true` lets anything through, so there's nothing to go off of
+        Schema::Bool(_) => value.clone(),
+    }
+}
+
+fn index_object(value: &Value, schema: &SchemaObject, root: &RootSchema) -> Value {
+    if let Some(reference) = &schema.reference {
+        let name = reference.trim_start_matches("#/definitions/");
+        let definition = &root.definitions[name];
+        if let (Some(key), Schema::Object(map)) = (enum_map_key(name), definition) {
+            // An EnumMap, so the keys can go
+            let keys = match &root.definitions[key] {
+                Schema::Object(key) => key.enum_values.clone().unwrap_or_default(),
+                Schema::Bool(_) => Vec::new(),
+            };
+            let value_schema = map
+                .object
+                .as_ref()
+                .and_then(|obj| obj.properties.values().next());
+            return Value::Array(
+                keys.iter()
+                    .map(|key| {
+                        let value = &value[key.as_str().unwrap()];
+                        match value_schema {
+                            Some(schema) => index(value, schema, root),
+                            None => value.clone(),
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        return index(value, definition, root);
+    }
+
+    if let (Some(variants), Value::String(_)) = (&schema.enum_values, value) {
+        if let Some(idx) = variants.iter().position(|variant| variant == value) {
+            return idx.into();
+        }
+    }
+
+    if let Some(subschemas) = &schema.subschemas {
+        // Options and enums with data; use whichever one fits
+        let options = subschemas
+            .any_of
+            .iter()
+            .chain(subschemas.one_of.iter())
+            .chain(subschemas.all_of.iter())
+            .flatten();
+        for option in options {
+            if fits(value, option, root) {
+                return index(value, option, root);
+            }
+        }
+        return value.clone();
+    }
+
+    match value {
+        Value::Object(fields) => match &schema.object {
+            Some(obj) if !obj.properties.is_empty() => Value::Array(
+                obj.properties
+                    .iter()
+                    .map(|(name, field)| match fields.get(name) {
+                        Some(value) => index(value, field, root),
+                        None => Value::Null,
+                    })
+                    .collect(),
+            ),
+            _ => value.clone(),
+        },
+        Value::Array(elements) => match schema.array.as_ref().and_then(|arr| arr.items.as_ref()) {
+            Some(SingleOrVec::Single(item)) => Value::Array(
+                elements
+                    .iter()
+                    .map(|element| index(element, item, root))
+                    .collect(),
+            ),
+            // Tuples, like building sizes, are just numbers
+            _ => value.clone(),
+        },
+        _ => value.clone(),
+    }
+}
+
+/// Roughly check whether the value could be this schema, to pick out of an `anyOf`.
+fn fits(value: &Value, schema: &Schema, root: &RootSchema) -> bool {
+    let schema = match schema {
+        Schema::Object(schema) => schema,
+        Schema::Bool(anything) => return *anything,
+    };
+    if let Some(reference) = &schema.reference {
+        let name = reference.trim_start_matches("#/definitions/");
+        return fits(value, &root.definitions[name], root);
+    }
+    if let Some(variants) = &schema.enum_values {
+        return variants.contains(value);
+    }
+    if let Some(obj) = &schema.object {
+        return match value {
+            Value::Object(fields) => obj.required.iter().all(|name| fields.contains_key(name)),
+            _ => false,
+        };
+    }
+    match &schema.instance_type {
+        Some(SingleOrVec::Single(ty)) => {
+            use schemars::schema::InstanceType::*;
+            matches!(
+                (**ty, value),
+                (Null, Value::Null)
+                    | (Boolean, Value::Bool(_))
+                    | (Number, Value::Number(_))
+                    | (Integer, Value::Number(_))
+                    | (String, Value::String(_))
+                    | (Array, Value::Array(_))
+            )
+        }
+        _ => true,
+    }
+}
+
+/// Get the key enum's name out of a `SchemingEnumMap` schema name.
+fn enum_map_key(name: &str) -> Option<&str> {
+    let rest = name.strip_prefix("EnumMap_of_")?;
+    Some(&rest[..rest.find("_to_")?])
+}
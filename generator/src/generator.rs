@@ -1,8 +1,13 @@
 use crate::{
+    buildings::Building,
     items::{Item, ItemStack},
+    names::DisplayNames,
+    power,
     producers::Producer,
     recipes::Recipe,
+    schema::SCHEMA_VERSION,
     schenum_map::SchemingEnumMap,
+    tech_layout::{TechLayout, TechPosition},
     technologies::Technology,
 };
 
@@ -16,10 +21,20 @@ use std::fmt::Debug;
 #[derive(Debug, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AllDSPInfo {
+    /// Which version of this shape this is; see `schema::SCHEMA_VERSION`.
+    pub schema_version: u32,
     pub tech_tree: SchemingEnumMap<Technology, TechnologyEntry>,
     pub recipes: SchemingEnumMap<Recipe, RecipeEntry>,
     pub production_methods: SchemingEnumMap<Item, Vec<Recipe>>,
     pub consumption_methods: SchemingEnumMap<Item, Vec<Recipe>>,
+    /// Where to draw each technology in the tech tree.
+    pub tech_layout: SchemingEnumMap<Technology, TechPosition>,
+    /// Info about each building, or null for items that aren't buildings.
+    pub buildings: SchemingEnumMap<Item, Option<Building>>,
+    /// Joules each item gives when burned, or null for items that aren't fuel.
+    pub fuel_energy: SchemingEnumMap<Item, Option<f64>>,
+    /// What the game calls everything.
+    pub names: DisplayNames,
 }
 
 impl AllDSPInfo {
@@ -28,22 +43,51 @@ impl AllDSPInfo {
         let recipes = RecipeEntry::generate_all();
         let (production_methods, consumption_methods) = generate_usages(&recipes);
         Self {
+            schema_version: SCHEMA_VERSION,
             tech_tree: TechnologyEntry::generate_all().into(),
             recipes: recipes.into(),
             production_methods: production_methods.into(),
             consumption_methods: consumption_methods.into(),
+            tech_layout: TechLayout::generate().positions.into(),
+            buildings: EnumMap::from(Item::building).into(),
+            fuel_energy: EnumMap::from(power::fuel_energy).into(),
+            names: DisplayNames::generate(),
         }
     }
+
+    /// Get every item that no recipe makes any of.
+    pub fn unproducible_items(&self) -> Vec<Item> {
+        Item::iter()
+            .filter(|&item| {
+                !self.production_methods.0[item].iter().any(|&recipe| {
+                    self.recipes.0[recipe]
+                        .results
+                        .iter()
+                        .any(|stack| stack.item == item && stack.count > 0.0)
+                })
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TechnologyEntry {
-    tech: Technology,
+    pub tech: Technology,
     /// All technology that this immediately depends on
-    prereqs: Vec<Technology>,
+    pub prereqs: Vec<Technology>,
     /// All technology immediately depending on this
-    postreqs: Vec<Technology>,
+    pub postreqs: Vec<Technology>,
+    /// All technology that this depends on, immediately or not
+    pub all_prereqs: Vec<Technology>,
+    /// All technology depending on this, immediately or not
+    pub all_postreqs: Vec<Technology>,
+    /// How many techs deep this is from `DysonSphereProgram`, going the longest way
+    pub depth: u32,
+    /// The most techs there could be between this and `MissionCompleted`, counting `MissionCompleted`.
+    ///
+    /// This is `None` if `MissionCompleted` doesn't depend on this.
+    pub steps_to_completion: Option<u32>,
 }
 
 impl TechnologyEntry {
@@ -57,12 +101,37 @@ impl TechnologyEntry {
             }
         }
 
+        let all_prereqs = EnumMap::from(Technology::all_prerequisites);
+        let depths = EnumMap::from(Technology::depth);
+
+        // Go from the deepest techs up so all the postreqs are done first
+        let mut by_depth: Vec<_> = Technology::iter().collect();
+        by_depth.sort_by_key(|&tech| std::cmp::Reverse(depths[tech]));
+        let mut steps_to_completion: EnumMap<Technology, Option<u32>> = EnumMap::from(|_| None);
+        for tech in by_depth {
+            steps_to_completion[tech] = if tech == Technology::MissionCompleted {
+                Some(0)
+            } else {
+                postreqs[tech]
+                    .iter()
+                    .filter_map(|&post: &Technology| steps_to_completion[post])
+                    .max()
+                    .map(|steps| steps + 1)
+            };
+        }
+
         // Sadly, I can't prove to EnumMap that I can map over the postreqs
         // so we have to clone instead
         EnumMap::from(|tech| TechnologyEntry {
             tech,
             prereqs: tech.prerequisites().to_vec(),
             postreqs: postreqs[tech].clone(),
+            all_prereqs: all_prereqs[tech].clone(),
+            all_postreqs: Technology::iter()
+                .filter(|&other| all_prereqs[other].contains(&tech))
+                .collect(),
+            depth: depths[tech],
+            steps_to_completion: steps_to_completion[tech],
         })
     }
 }
@@ -70,14 +139,14 @@ impl TechnologyEntry {
 #[derive(Debug, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RecipeEntry {
-    recipe: Recipe,
-    ingredients: Vec<ItemStack>,
+    pub recipe: Recipe,
+    pub ingredients: Vec<ItemStack>,
     /// Time in seconds to make it
-    time: f32,
-    results: Vec<ItemStack>,
-    made_in: Producer,
-    handcraftable: bool,
-    unlocked_by: Technology,
+    pub time: f32,
+    pub results: Vec<ItemStack>,
+    pub made_in: Producer,
+    pub handcraftable: bool,
+    pub unlocked_by: Technology,
 }
 
 struct PartialRecipeEntry {
@@ -163,7 +232,7 @@ impl RecipeEntry {
                     results: vec![is(Item::WindTurbine, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::Electromagnetism,
                 },
                 Recipe::TeslaTower => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 2), is(Item::Electromagnet, 1)],
@@ -171,15 +240,15 @@ impl RecipeEntry {
                     results: vec![is(Item::TeslaTower, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::Electromagnetism,
                 },
                 Recipe::ElectromagneticMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::Electromagnet, 1), is(Item::CircuitBoard, 1)],
                     time: 3.00,
                     results: vec![is(Item::ElectromagneticMatrix, 1)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::ElectromagneticMatrix,
                 },
                 Recipe::MatrixLab => PartialRecipeEntry {
                     ingredients: vec![
@@ -192,7 +261,7 @@ impl RecipeEntry {
                     results: vec![is(Item::MatrixLab, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ElectromagneticMatrix,
                 },
                 Recipe::Prism => PartialRecipeEntry {
                     ingredients: vec![is(Item::Glass, 3)],
@@ -200,7 +269,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Prism, 2)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighEfficiencyPlasmaControl,
                 },
                 Recipe::PlasmaExciter => PartialRecipeEntry {
                     ingredients: vec![is(Item::Electromagnet, 4), is(Item::Prism, 2)],
@@ -208,7 +277,7 @@ impl RecipeEntry {
                     results: vec![is(Item::PlasmaExciter, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighEfficiencyPlasmaControl,
                 },
                 Recipe::WirelessPowerTower => PartialRecipeEntry {
                     ingredients: vec![is(Item::TeslaTower, 1), is(Item::PlasmaExciter, 3)],
@@ -216,7 +285,7 @@ impl RecipeEntry {
                     results: vec![is(Item::WirelessPowerTower, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighEfficiencyPlasmaControl,
                 },
                 Recipe::OilExtractor => PartialRecipeEntry {
                     ingredients: vec![
@@ -229,7 +298,7 @@ impl RecipeEntry {
                     results: vec![is(Item::OilExtractor, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PlasmaExtractRefining,
                 },
                 Recipe::OilRefinery => PartialRecipeEntry {
                     ingredients: vec![
@@ -242,15 +311,15 @@ impl RecipeEntry {
                     results: vec![is(Item::OilRefinery, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PlasmaExtractRefining,
                 },
                 Recipe::PlasmaRefining => PartialRecipeEntry {
                     ingredients: vec![is(Item::CrudeOil, 2)],
                     time: 4.00,
                     results: vec![is(Item::Hydrogen, 1), is(Item::RefinedOil, 2)],
                     made_in: Producer::OilRefinery,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::PlasmaExtractRefining,
                 },
                 Recipe::GraphiteSmelting => PartialRecipeEntry {
                     ingredients: vec![is(Item::CoalOre, 2)],
@@ -258,15 +327,15 @@ impl RecipeEntry {
                     results: vec![is(Item::EnergeticGraphite, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SmeltingPurification,
                 },
                 Recipe::EnergyMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::EnergeticGraphite, 2), is(Item::Hydrogen, 2)],
                     time: 6.00,
                     results: vec![is(Item::EnergyMatrix, 1)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::EnergyMatrix,
                 },
                 Recipe::HydrogenFuelRod => PartialRecipeEntry {
                     ingredients: vec![is(Item::TitaniumIngot, 1), is(Item::Hydrogen, 5)],
@@ -274,7 +343,7 @@ impl RecipeEntry {
                     results: vec![is(Item::HydrogenFuelRod, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HydrogenFuelRod,
                 },
                 Recipe::Thruster => PartialRecipeEntry {
                     ingredients: vec![is(Item::Steel, 2), is(Item::CopperIngot, 3)],
@@ -282,7 +351,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Thruster, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::Thruster,
                 },
                 Recipe::ReinforcedThruster => PartialRecipeEntry {
                     ingredients: vec![
@@ -293,7 +362,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ReinforcedThruster, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ReinforcedThruster,
                 },
                 Recipe::ChemicalPlant => PartialRecipeEntry {
                     ingredients: vec![
@@ -306,15 +375,15 @@ impl RecipeEntry {
                     results: vec![is(Item::ChemicalPlant, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::BasicChemicalEngineering,
                 },
                 Recipe::Plastic => PartialRecipeEntry {
                     ingredients: vec![is(Item::RefinedOil, 2), is(Item::EnergeticGraphite, 1)],
                     time: 3.00,
                     results: vec![is(Item::Plastic, 1)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::BasicChemicalEngineering,
                 },
                 Recipe::SulfuricAcidFromStone => PartialRecipeEntry {
                     ingredients: vec![
@@ -325,8 +394,8 @@ impl RecipeEntry {
                     time: 6.00,
                     results: vec![is(Item::SulfuricAcid, 4)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::BasicChemicalEngineering,
                 },
                 Recipe::OrganicCrystalFromPlastic => PartialRecipeEntry {
                     ingredients: vec![
@@ -337,8 +406,8 @@ impl RecipeEntry {
                     time: 6.00,
                     results: vec![is(Item::OrganicCrystal, 1)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::PolymerChemicalEngineering,
                 },
                 Recipe::TitaniumCrystal => PartialRecipeEntry {
                     ingredients: vec![is(Item::OrganicCrystal, 1), is(Item::TitaniumIngot, 3)],
@@ -346,15 +415,15 @@ impl RecipeEntry {
                     results: vec![is(Item::TitaniumCrystal, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighStrengthCrystal,
                 },
                 Recipe::StructureMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::Diamond, 1), is(Item::TitaniumCrystal, 1)],
                     time: 8.00,
                     results: vec![is(Item::StructureMatrix, 1)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::StructureMatrix,
                 },
                 Recipe::CasimirCrystalFromTitanium => PartialRecipeEntry {
                     ingredients: vec![
@@ -366,7 +435,7 @@ impl RecipeEntry {
                     results: vec![is(Item::CasimirCrystal, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CasimirCrystal,
                 },
                 Recipe::CasimirCrystalFromOpticalGratingCrystal => PartialRecipeEntry {
                     ingredients: vec![
@@ -378,7 +447,7 @@ impl RecipeEntry {
                     results: vec![is(Item::CasimirCrystal, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CasimirCrystal,
                 },
                 Recipe::TitaniumGlass => PartialRecipeEntry {
                     ingredients: vec![
@@ -390,31 +459,31 @@ impl RecipeEntry {
                     results: vec![is(Item::TitaniumGlass, 2)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighStrengthGlass,
                 },
                 Recipe::GrapheneFromGraphiteAndSulfuric => PartialRecipeEntry {
                     ingredients: vec![is(Item::EnergeticGraphite, 3), is(Item::SulfuricAcid, 1)],
                     time: 3.00,
                     results: vec![is(Item::Graphene, 2)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::AppliedSuperconductor,
                 },
                 Recipe::GrapheneFromFireIce => PartialRecipeEntry {
                     ingredients: vec![is(Item::FireIce, 2)],
                     time: 2.00,
                     results: vec![is(Item::Graphene, 2), is(Item::Hydrogen, 1)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::AppliedSuperconductor,
                 },
                 Recipe::CarbonNanotubeFromGraphene => PartialRecipeEntry {
                     ingredients: vec![is(Item::Graphene, 3), is(Item::TitaniumIngot, 1)],
                     time: 4.00,
                     results: vec![is(Item::CarbonNanotube, 2)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::HighStrengthMaterial,
                 },
                 Recipe::SiliconOreFromStone => PartialRecipeEntry {
                     ingredients: vec![is(Item::StoneOre, 10)],
@@ -422,15 +491,15 @@ impl RecipeEntry {
                     results: vec![is(Item::SiliconOre, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SemiconductorMaterial,
                 },
                 Recipe::CarbonNanotubeFromSpiniform => PartialRecipeEntry {
                     ingredients: vec![is(Item::SpiniformStalagmiteCrystal, 2)],
                     time: 4.00,
                     results: vec![is(Item::CarbonNanotube, 2)],
                     made_in: Producer::ChemicalPlant,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::HighStrengthMaterial,
                 },
                 Recipe::ParticleBroadband => PartialRecipeEntry {
                     ingredients: vec![
@@ -442,7 +511,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ParticleBroadband, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::InformationMatrix,
                 },
                 Recipe::CrystalSiliconFromIngot => PartialRecipeEntry {
                     ingredients: vec![is(Item::HighPuritySilicon, 1)],
@@ -450,7 +519,7 @@ impl RecipeEntry {
                     results: vec![is(Item::CrystalSilicon, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CrystalSmelting,
                 },
                 Recipe::PlaneFilter => PartialRecipeEntry {
                     ingredients: vec![is(Item::CasimirCrystal, 1), is(Item::TitaniumGlass, 2)],
@@ -458,7 +527,7 @@ impl RecipeEntry {
                     results: vec![is(Item::PlaneFilter, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::WaveFunctionInterference,
                 },
                 Recipe::MiniatureParticleCollider => PartialRecipeEntry {
                     ingredients: vec![
@@ -472,15 +541,15 @@ impl RecipeEntry {
                     results: vec![is(Item::MiniatureParticleCollider, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::MiniatureParticleCollider,
                 },
                 Recipe::DeuteriumInParticleCollider => PartialRecipeEntry {
                     ingredients: vec![is(Item::Hydrogen, 10)],
                     time: 5.00,
                     results: vec![is(Item::Deuterium, 5)],
                     made_in: Producer::MiniatureParticleCollider,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::MiniatureParticleCollider,
                 },
                 Recipe::DeuteronFuelRod => PartialRecipeEntry {
                     ingredients: vec![
@@ -492,7 +561,7 @@ impl RecipeEntry {
                     results: vec![is(Item::DeuteronFuelRod, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::MiniFusionPowerGeneration,
                 },
                 Recipe::AnnihilationConstraintSphere => PartialRecipeEntry {
                     ingredients: vec![is(Item::ParticleContainer, 1), is(Item::Processor, 1)],
@@ -500,7 +569,7 @@ impl RecipeEntry {
                     results: vec![is(Item::AnnihilationConstraintSphere, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ControlledAnnihilationReaction,
                 },
                 Recipe::ArtificialStar => PartialRecipeEntry {
                     ingredients: vec![
@@ -513,7 +582,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ArtificialStar, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ArtificialStar,
                 },
                 Recipe::AntimatterFuelRod => PartialRecipeEntry {
                     ingredients: vec![
@@ -526,7 +595,7 @@ impl RecipeEntry {
                     results: vec![is(Item::AntimatterFuelRod, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ControlledAnnihilationReaction,
                 },
                 Recipe::AssemblingMachineMK1 => PartialRecipeEntry {
                     ingredients: vec![
@@ -538,7 +607,7 @@ impl RecipeEntry {
                     results: vec![is(Item::AssemblingMachineMK1, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::BasicAssemblingProcesses,
                 },
                 Recipe::AssemblingMachineMK2 => PartialRecipeEntry {
                     ingredients: vec![
@@ -550,7 +619,7 @@ impl RecipeEntry {
                     results: vec![is(Item::AssemblingMachineMK2, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighSpeedAssemblingProcesses,
                 },
                 Recipe::AssemblingMachineMK3 => PartialRecipeEntry {
                     ingredients: vec![
@@ -562,7 +631,7 @@ impl RecipeEntry {
                     results: vec![is(Item::AssemblingMachineMK3, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::QuantumPrintingTechnology,
                 },
                 Recipe::MiningMachine => PartialRecipeEntry {
                     ingredients: vec![
@@ -575,7 +644,7 @@ impl RecipeEntry {
                     results: vec![is(Item::MiningMachine, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::Electromagnetism,
                 },
                 Recipe::WaterPump => PartialRecipeEntry {
                     ingredients: vec![
@@ -588,7 +657,7 @@ impl RecipeEntry {
                     results: vec![is(Item::WaterPump, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::FluidStorageEncapsulation,
                 },
                 Recipe::CircuitBoard => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 2), is(Item::CopperIngot, 1)],
@@ -607,7 +676,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Processor, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::Processor,
                 },
                 Recipe::QuantumChip => PartialRecipeEntry {
                     ingredients: vec![is(Item::Processor, 2), is(Item::PlaneFilter, 2)],
@@ -615,7 +684,7 @@ impl RecipeEntry {
                     results: vec![is(Item::QuantumChip, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::QuantumChip,
                 },
                 Recipe::MicrocrystallineComponent => PartialRecipeEntry {
                     ingredients: vec![is(Item::HighPuritySilicon, 2), is(Item::CopperIngot, 1)],
@@ -623,7 +692,7 @@ impl RecipeEntry {
                     results: vec![is(Item::MicrocrystallineComponent, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SemiconductorMaterial,
                 },
                 Recipe::OrganicCrystalFromWood => PartialRecipeEntry {
                     ingredients: vec![
@@ -635,15 +704,15 @@ impl RecipeEntry {
                     results: vec![is(Item::OrganicCrystal, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PolymerChemicalEngineering,
                 },
                 Recipe::InformationMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::Processor, 2), is(Item::ParticleBroadband, 1)],
                     time: 10.00,
                     results: vec![is(Item::InformationMatrix, 1)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::InformationMatrix,
                 },
                 Recipe::Smelter => PartialRecipeEntry {
                     ingredients: vec![
@@ -656,7 +725,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Smelter, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::AutomaticMetallurgy,
                 },
                 Recipe::GlassSmelting => PartialRecipeEntry {
                     ingredients: vec![is(Item::StoneOre, 2)],
@@ -664,15 +733,15 @@ impl RecipeEntry {
                     results: vec![is(Item::Glass, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::AutomaticMetallurgy,
                 },
                 Recipe::XRayCracking => PartialRecipeEntry {
                     ingredients: vec![is(Item::RefinedOil, 1), is(Item::Hydrogen, 2)],
                     time: 4.00,
                     results: vec![is(Item::Hydrogen, 3), is(Item::EnergeticGraphite, 1)],
                     made_in: Producer::OilRefinery,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::XRayCracking,
                 },
                 Recipe::SiliconSmelting => PartialRecipeEntry {
                     ingredients: vec![is(Item::SiliconOre, 2)],
@@ -680,7 +749,7 @@ impl RecipeEntry {
                     results: vec![is(Item::HighPuritySilicon, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SmeltingPurification,
                 },
                 Recipe::DiamondFromGraphite => PartialRecipeEntry {
                     ingredients: vec![is(Item::EnergeticGraphite, 1)],
@@ -688,7 +757,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Diamond, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CrystalSmelting,
                 },
                 Recipe::DiamondFromKimberlite => PartialRecipeEntry {
                     ingredients: vec![is(Item::Kimberlite, 1)],
@@ -696,7 +765,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Diamond, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CrystalSmelting,
                 },
                 Recipe::CrystalSiliconFromFractal => PartialRecipeEntry {
                     ingredients: vec![is(Item::FractalSilicon, 1)],
@@ -704,7 +773,7 @@ impl RecipeEntry {
                     results: vec![is(Item::CrystalSilicon, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::CrystalSmelting,
                 },
                 Recipe::SteelSmelting => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 3)],
@@ -712,7 +781,28 @@ impl RecipeEntry {
                     results: vec![is(Item::Steel, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SteelSmelting,
+                },
+                Recipe::MiniFusionPowerStation => PartialRecipeEntry {
+                    ingredients: vec![
+                        is(Item::TitaniumAlloy, 12),
+                        is(Item::SuperMagneticRing, 10),
+                        is(Item::CarbonNanotube, 8),
+                        is(Item::Processor, 4),
+                    ],
+                    time: 10.00,
+                    results: vec![is(Item::MiniFusionPowerStation, 1)],
+                    made_in: Producer::AssemblingMachine,
+                    handcraftable: true,
+                    unlocked_by: Technology::MiniFusionPowerGeneration,
+                },
+                Recipe::SolarSail => PartialRecipeEntry {
+                    ingredients: vec![is(Item::Graphene, 1), is(Item::PhotonCombiner, 1)],
+                    time: 4.00,
+                    results: vec![is(Item::SolarSail, 2)],
+                    made_in: Producer::AssemblingMachine,
+                    handcraftable: true,
+                    unlocked_by: Technology::SolarSailOrbitSystem,
                 },
                 Recipe::ThermalPowerStation => PartialRecipeEntry {
                     ingredients: vec![
@@ -725,7 +815,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ThermalPowerStation, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ThermalPower,
                 },
                 Recipe::TitaniumSmelting => PartialRecipeEntry {
                     ingredients: vec![is(Item::TitaniumOre, 2)],
@@ -733,7 +823,7 @@ impl RecipeEntry {
                     results: vec![is(Item::TitaniumIngot, 1)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::TitaniumSmelting,
                 },
                 Recipe::TitaniumAlloy => PartialRecipeEntry {
                     ingredients: vec![
@@ -745,7 +835,7 @@ impl RecipeEntry {
                     results: vec![is(Item::TitaniumAlloy, 4)],
                     made_in: Producer::Smelter,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighStrengthTitaniumAlloy,
                 },
                 Recipe::SolarPanel => PartialRecipeEntry {
                     ingredients: vec![
@@ -757,7 +847,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SolarPanel, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SolarCollection,
                 },
                 Recipe::PhotonCombinerFromPrism => PartialRecipeEntry {
                     ingredients: vec![is(Item::Prism, 2), is(Item::CircuitBoard, 1)],
@@ -765,7 +855,7 @@ impl RecipeEntry {
                     results: vec![is(Item::PhotonCombiner, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PhotonFrequencyConversion,
                 },
                 Recipe::PhotonCombinerFromCrystal => PartialRecipeEntry {
                     ingredients: vec![
@@ -776,7 +866,7 @@ impl RecipeEntry {
                     results: vec![is(Item::PhotonCombiner, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PhotonFrequencyConversion,
                 },
                 Recipe::EMRailEjector => PartialRecipeEntry {
                     ingredients: vec![
@@ -789,7 +879,7 @@ impl RecipeEntry {
                     results: vec![is(Item::EMRailEjector, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SolarSailOrbitSystem,
                 },
                 Recipe::RayReceiver => PartialRecipeEntry {
                     ingredients: vec![
@@ -803,7 +893,7 @@ impl RecipeEntry {
                     results: vec![is(Item::RayReceiver, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::RayReceiver,
                 },
                 Recipe::SatelliteSubstation => PartialRecipeEntry {
                     ingredients: vec![
@@ -815,15 +905,15 @@ impl RecipeEntry {
                     results: vec![is(Item::SatelliteSubstation, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SatellitePowerDistributionSystem,
                 },
                 Recipe::DiracInversion => PartialRecipeEntry {
                     ingredients: vec![is(Item::CriticalPhoton, 2)],
                     time: 2.00,
                     results: vec![is(Item::Antimatter, 2), is(Item::Hydrogen, 2)],
                     made_in: Producer::MiniatureParticleCollider,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::DiracInversionMechanism,
                 },
                 Recipe::UniverseMatrix => PartialRecipeEntry {
                     ingredients: vec![
@@ -837,8 +927,8 @@ impl RecipeEntry {
                     time: 15.00,
                     results: vec![is(Item::UniverseMatrix, 1)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::UniverseMatrix,
                 },
                 Recipe::Accumulator => PartialRecipeEntry {
                     ingredients: vec![
@@ -850,7 +940,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Accumulator, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::EnergyStorage,
                 },
                 Recipe::EnergyExchanger => PartialRecipeEntry {
                     ingredients: vec![
@@ -863,7 +953,7 @@ impl RecipeEntry {
                     results: vec![is(Item::EnergyExchanger, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::InterstellarPowerTransmission,
                 },
                 Recipe::SpaceWarperFromLens => PartialRecipeEntry {
                     ingredients: vec![is(Item::GravitonLens, 1)],
@@ -871,7 +961,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SpaceWarper, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::GravitationalWaveRefraction,
                 },
                 Recipe::SpaceWarperFromMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::GravityMatrix, 1)],
@@ -879,7 +969,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SpaceWarper, 8)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::GravitationalWaveRefraction,
                 },
                 Recipe::FrameMaterial => PartialRecipeEntry {
                     ingredients: vec![
@@ -891,7 +981,7 @@ impl RecipeEntry {
                     results: vec![is(Item::FrameMaterial, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighStrengthLightweightStructure,
                 },
                 Recipe::DysonSphereComponent => PartialRecipeEntry {
                     ingredients: vec![
@@ -903,7 +993,7 @@ impl RecipeEntry {
                     results: vec![is(Item::DysonSphereComponent, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::VerticalLaunchingSilo,
                 },
                 Recipe::VerticalLaunchingSilo => PartialRecipeEntry {
                     ingredients: vec![
@@ -916,7 +1006,7 @@ impl RecipeEntry {
                     results: vec![is(Item::VerticalLaunchingSilo, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::VerticalLaunchingSilo,
                 },
                 Recipe::SmallCarrierRocket => PartialRecipeEntry {
                     ingredients: vec![
@@ -928,7 +1018,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SmallCarrierRocket, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::VerticalLaunchingSilo,
                 },
                 Recipe::ConveyorMK1 => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 2), is(Item::Gear, 1)],
@@ -936,7 +1026,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ConveyorMK1, 3)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::BasicLogisticsSystem,
                 },
                 Recipe::SorterMK1 => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 1), is(Item::CircuitBoard, 1)],
@@ -944,7 +1034,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SorterMK1, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::BasicLogisticsSystem,
                 },
                 Recipe::StorageMK1 => PartialRecipeEntry {
                     ingredients: vec![is(Item::IronIngot, 4), is(Item::Stone, 4)],
@@ -952,7 +1042,7 @@ impl RecipeEntry {
                     results: vec![is(Item::StorageMK1, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::BasicLogisticsSystem,
                 },
                 Recipe::Splitter => PartialRecipeEntry {
                     ingredients: vec![
@@ -964,7 +1054,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Splitter, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ImprovedLogisticsSystem,
                 },
                 Recipe::SorterMK2 => PartialRecipeEntry {
                     ingredients: vec![is(Item::SorterMK1, 2), is(Item::Motor, 1)],
@@ -972,7 +1062,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SorterMK2, 2)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ImprovedLogisticsSystem,
                 },
                 Recipe::ConveyorMK2 => PartialRecipeEntry {
                     ingredients: vec![
@@ -983,7 +1073,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ConveyorMK2, 3)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ImprovedLogisticsSystem,
                 },
                 Recipe::SorterMK3 => PartialRecipeEntry {
                     ingredients: vec![is(Item::SorterMK2, 2), is(Item::ElectromagneticTurbine, 1)],
@@ -991,7 +1081,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SorterMK3, 2)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighEfficiencyLogisticsSystem,
                 },
                 Recipe::StorageMK2 => PartialRecipeEntry {
                     ingredients: vec![is(Item::Steel, 8), is(Item::Stone, 8)],
@@ -999,7 +1089,7 @@ impl RecipeEntry {
                     results: vec![is(Item::StorageMK2, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ImprovedLogisticsSystem,
                 },
                 Recipe::ConveyorMK3 => PartialRecipeEntry {
                     ingredients: vec![
@@ -1011,7 +1101,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ConveyorMK3, 3)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::HighEfficiencyLogisticsSystem,
                 },
                 Recipe::PlanetaryLogisticsStation => PartialRecipeEntry {
                     ingredients: vec![
@@ -1024,7 +1114,7 @@ impl RecipeEntry {
                     results: vec![is(Item::PlanetaryLogisticsStation, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PlanetaryLogisticsSystem,
                 },
                 Recipe::LogisticsDrone => PartialRecipeEntry {
                     ingredients: vec![
@@ -1036,7 +1126,7 @@ impl RecipeEntry {
                     results: vec![is(Item::LogisticsDrone, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::PlanetaryLogisticsSystem,
                 },
                 Recipe::InterstellarLogisticsStation => PartialRecipeEntry {
                     ingredients: vec![
@@ -1048,7 +1138,7 @@ impl RecipeEntry {
                     results: vec![is(Item::InterstellarLogisticsStation, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::InterstellarLogisticsSystem,
                 },
                 Recipe::LogisticsVessel => PartialRecipeEntry {
                     ingredients: vec![
@@ -1060,7 +1150,7 @@ impl RecipeEntry {
                     results: vec![is(Item::LogisticsVessel, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::InterstellarLogisticsSystem,
                 },
                 Recipe::Motor => PartialRecipeEntry {
                     ingredients: vec![
@@ -1072,7 +1162,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Motor, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::ElectromagneticDrive,
                 },
                 Recipe::ElectromagneticTurbine => PartialRecipeEntry {
                     ingredients: vec![is(Item::Motor, 2), is(Item::Electromagnet, 2)],
@@ -1080,7 +1170,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ElectromagneticTurbine, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::MagneticLevitationTechnology,
                 },
                 Recipe::ParticleContainerFromEMTurbine => PartialRecipeEntry {
                     ingredients: vec![
@@ -1092,7 +1182,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ParticleContainer, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::MagneticParticleTrap,
                 },
                 Recipe::ParticleContainerFromUnipolar => PartialRecipeEntry {
                     ingredients: vec![is(Item::UnipolarMagnet, 10), is(Item::CopperIngot, 2)],
@@ -1100,7 +1190,7 @@ impl RecipeEntry {
                     results: vec![is(Item::ParticleContainer, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::MagneticParticleTrap,
                 },
                 Recipe::GravitonLens => PartialRecipeEntry {
                     ingredients: vec![is(Item::Diamond, 4), is(Item::StrangeMatter, 1)],
@@ -1108,15 +1198,15 @@ impl RecipeEntry {
                     results: vec![is(Item::GravitonLens, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::GravitationalWaveRefraction,
                 },
                 Recipe::GravityMatrix => PartialRecipeEntry {
                     ingredients: vec![is(Item::GravitonLens, 1), is(Item::QuantumChip, 1)],
                     time: 24.00,
                     results: vec![is(Item::GravityMatrix, 2)],
                     made_in: Producer::MatrixLab,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::GravityMatrix,
                 },
                 Recipe::SuperMagneticRing => PartialRecipeEntry {
                     ingredients: vec![
@@ -1128,7 +1218,7 @@ impl RecipeEntry {
                     results: vec![is(Item::SuperMagneticRing, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::SuperMagneticFieldGenerator,
                 },
                 Recipe::StrangeMatter => PartialRecipeEntry {
                     ingredients: vec![
@@ -1139,8 +1229,8 @@ impl RecipeEntry {
                     time: 8.00,
                     results: vec![is(Item::StrangeMatter, 1)],
                     made_in: Producer::MiniatureParticleCollider,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::StrangeMatter,
                 },
                 // accelerators and spray painters are nyi
                 Recipe::Fractionator => PartialRecipeEntry {
@@ -1154,7 +1244,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Fractionator, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::DeuteriumFractionation,
                 },
                 Recipe::OrbitCollector => PartialRecipeEntry {
                     ingredients: vec![
@@ -1167,7 +1257,7 @@ impl RecipeEntry {
                     results: vec![is(Item::OrbitCollector, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::GasGiantsExplotiation,
                 },
                 Recipe::Foundation => PartialRecipeEntry {
                     ingredients: vec![is(Item::Stone, 3), is(Item::Steel, 1)],
@@ -1175,7 +1265,7 @@ impl RecipeEntry {
                     results: vec![is(Item::Foundation, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::EnvironmentModification,
                 },
                 Recipe::StorageTank => PartialRecipeEntry {
                     ingredients: vec![
@@ -1187,22 +1277,22 @@ impl RecipeEntry {
                     results: vec![is(Item::StorageTank, 1)],
                     made_in: Producer::AssemblingMachine,
                     handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    unlocked_by: Technology::FluidStorageEncapsulation,
                 },
                 Recipe::DeuteriumFractionation => PartialRecipeEntry {
                     ingredients: vec![is(Item::Hydrogen, 1)],
                     time: 1.00,
                     results: vec![isf(Item::Deuterium, 0.01), isf(Item::Hydrogen, 0.99)],
                     made_in: Producer::Fractionator,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::DeuteriumFractionation,
                 },
                 Recipe::IronMining => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 2.00,
                     results: vec![is(Item::IronOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::CopperMining => PartialRecipeEntry {
@@ -1210,7 +1300,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::CopperOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::SiliconMining => PartialRecipeEntry {
@@ -1218,7 +1308,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::SiliconOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::TitaniumMining => PartialRecipeEntry {
@@ -1226,7 +1316,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::TitaniumOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::StoneMining => PartialRecipeEntry {
@@ -1234,7 +1324,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::StoneOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::CoalMining => PartialRecipeEntry {
@@ -1242,7 +1332,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::CoalOre, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::FireIceMining => PartialRecipeEntry {
@@ -1250,7 +1340,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::FireIce, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::DiamondMining => PartialRecipeEntry {
@@ -1258,7 +1348,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::Kimberlite, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::FractalSiliconMining => PartialRecipeEntry {
@@ -1266,7 +1356,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::FractalSilicon, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::OrganicCrystalMining => PartialRecipeEntry {
@@ -1274,7 +1364,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::OrganicCrystal, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::OpticalGratingCrystalMining => PartialRecipeEntry {
@@ -1282,7 +1372,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::OpticalGratingCrystal, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::SpiniformStalagmiteCrystalMining => PartialRecipeEntry {
@@ -1290,7 +1380,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::SpiniformStalagmiteCrystal, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::UnipolarMagnetMining => PartialRecipeEntry {
@@ -1298,7 +1388,7 @@ impl RecipeEntry {
                     time: 2.00,
                     results: vec![is(Item::UnipolarMagnet, 1)],
                     made_in: Producer::MiningMachine,
-                    handcraftable: true,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
                 Recipe::OilExtraction => PartialRecipeEntry {
@@ -1306,49 +1396,82 @@ impl RecipeEntry {
                     time: 1.00,
                     results: vec![is(Item::CrudeOil, 1)],
                     made_in: Producer::OilExtractor,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::PlasmaExtractRefining,
                 },
                 Recipe::CriticalPhotonReceiving => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 1.00,
                     results: vec![is(Item::CriticalPhoton, 1)],
                     made_in: Producer::RayReceiver,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::RayReceiver,
                 },
                 Recipe::WaterPumping => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 1.20,
                     results: vec![is(Item::Water, 1)],
                     made_in: Producer::WaterPump,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::FluidStorageEncapsulation,
                 },
                 Recipe::SulfuricAcidPumping => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 1.20,
                     results: vec![is(Item::SulfuricAcid, 1)],
                     made_in: Producer::WaterPump,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::FluidStorageEncapsulation,
                 },
                 Recipe::GasGiantCollection => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 8.00,
                     results: vec![is(Item::Hydrogen, 4), is(Item::Deuterium, 0)],
                     made_in: Producer::OrbitCollector,
-                    handcraftable: true,
-                    unlocked_by: Technology::DysonSphereProgram,
+                    handcraftable: false,
+                    unlocked_by: Technology::GasGiantsExplotiation,
                 },
                 Recipe::IceGiantCollection => PartialRecipeEntry {
                     ingredients: vec![],
                     time: 8.00,
                     results: vec![is(Item::FireIce, 2), is(Item::Hydrogen, 1)],
                     made_in: Producer::OrbitCollector,
-                    handcraftable: true,
+                    handcraftable: false,
+                    unlocked_by: Technology::GasGiantsExplotiation,
+                },
+                Recipe::TreeHarvesting => PartialRecipeEntry {
+                    ingredients: vec![],
+                    time: 2.00,
+                    results: vec![is(Item::Log, 1)],
+                    made_in: Producer::Mecha,
+                    handcraftable: false,
+                    unlocked_by: Technology::DysonSphereProgram,
+                },
+                Recipe::PlantHarvesting => PartialRecipeEntry {
+                    ingredients: vec![],
+                    time: 2.00,
+                    results: vec![is(Item::PlantFuel, 1)],
+                    made_in: Producer::Mecha,
+                    handcraftable: false,
                     unlocked_by: Technology::DysonSphereProgram,
                 },
+                // These take 180 MJ at 45 MW
+                Recipe::ChargeAccumulator => PartialRecipeEntry {
+                    ingredients: vec![is(Item::Accumulator, 1)],
+                    time: 4.00,
+                    results: vec![is(Item::FullAccumulator, 1)],
+                    made_in: Producer::EnergyExchanger,
+                    handcraftable: false,
+                    unlocked_by: Technology::InterstellarPowerTransmission,
+                },
+                Recipe::DischargeAccumulator => PartialRecipeEntry {
+                    ingredients: vec![is(Item::FullAccumulator, 1)],
+                    time: 4.00,
+                    results: vec![is(Item::Accumulator, 1)],
+                    made_in: Producer::EnergyExchanger,
+                    handcraftable: false,
+                    unlocked_by: Technology::InterstellarPowerTransmission,
+                },
             };
             // Transform the entry
             RecipeEntry {
@@ -0,0 +1,420 @@
+use crate::{generator::AllDSPInfo, items::Item, recipes::Recipe, technologies::Technology};
+
+use strum::IntoEnumIterator;
+
+use std::path::Path;
+
+impl Item {
+    /// The ID the game uses for this item.
+    pub fn game_id(self) -> u32 {
+        match self {
+            Item::Water => 1000,
+            Item::IronOre => 1001,
+            Item::CopperOre => 1002,
+            Item::SiliconOre => 1003,
+            Item::TitaniumOre => 1004,
+            Item::StoneOre => 1005,
+            Item::CoalOre => 1006,
+            Item::CrudeOil => 1007,
+            Item::FireIce => 1011,
+            Item::Kimberlite => 1012,
+            Item::FractalSilicon => 1013,
+            Item::OpticalGratingCrystal => 1014,
+            Item::SpiniformStalagmiteCrystal => 1015,
+            Item::UnipolarMagnet => 1016,
+            Item::Log => 1030,
+            Item::PlantFuel => 1031,
+            Item::IronIngot => 1101,
+            Item::Magnet => 1102,
+            Item::Steel => 1103,
+            Item::CopperIngot => 1104,
+            Item::HighPuritySilicon => 1105,
+            Item::TitaniumIngot => 1106,
+            Item::TitaniumAlloy => 1107,
+            Item::Stone => 1108,
+            Item::EnergeticGraphite => 1109,
+            Item::Glass => 1110,
+            Item::Prism => 1111,
+            Item::Diamond => 1112,
+            Item::CrystalSilicon => 1113,
+            Item::RefinedOil => 1114,
+            Item::Plastic => 1115,
+            Item::SulfuricAcid => 1116,
+            Item::OrganicCrystal => 1117,
+            Item::TitaniumCrystal => 1118,
+            Item::TitaniumGlass => 1119,
+            Item::Hydrogen => 1120,
+            Item::Deuterium => 1121,
+            Item::Antimatter => 1122,
+            Item::Graphene => 1123,
+            Item::CarbonNanotube => 1124,
+            Item::FrameMaterial => 1125,
+            Item::CasimirCrystal => 1126,
+            Item::StrangeMatter => 1127,
+            Item::Foundation => 1131,
+            Item::Gear => 1201,
+            Item::Electromagnet => 1202,
+            Item::Motor => 1203,
+            Item::ElectromagneticTurbine => 1204,
+            Item::SuperMagneticRing => 1205,
+            Item::ParticleContainer => 1206,
+            Item::CriticalPhoton => 1208,
+            Item::GravitonLens => 1209,
+            Item::SpaceWarper => 1210,
+            Item::CircuitBoard => 1301,
+            Item::MicrocrystallineComponent => 1302,
+            Item::Processor => 1303,
+            Item::PlaneFilter => 1304,
+            Item::QuantumChip => 1305,
+            Item::PlasmaExciter => 1401,
+            Item::ParticleBroadband => 1402,
+            Item::AnnihilationConstraintSphere => 1403,
+            Item::PhotonCombiner => 1404,
+            Item::Thruster => 1405,
+            Item::ReinforcedThruster => 1406,
+            Item::SolarSail => 1501,
+            Item::DysonSphereComponent => 1502,
+            Item::SmallCarrierRocket => 1503,
+            Item::HydrogenFuelRod => 1801,
+            Item::DeuteronFuelRod => 1802,
+            Item::AntimatterFuelRod => 1803,
+            Item::ConveyorMK1 => 2001,
+            Item::ConveyorMK2 => 2002,
+            Item::ConveyorMK3 => 2003,
+            Item::SorterMK1 => 2011,
+            Item::SorterMK2 => 2012,
+            Item::SorterMK3 => 2013,
+            Item::Splitter => 2020,
+            Item::StorageMK1 => 2101,
+            Item::StorageMK2 => 2102,
+            Item::PlanetaryLogisticsStation => 2103,
+            Item::InterstellarLogisticsStation => 2104,
+            Item::OrbitCollector => 2105,
+            Item::StorageTank => 2106,
+            Item::TeslaTower => 2201,
+            Item::WirelessPowerTower => 2202,
+            Item::WindTurbine => 2203,
+            Item::ThermalPowerStation => 2204,
+            Item::SolarPanel => 2205,
+            Item::Accumulator => 2206,
+            Item::FullAccumulator => 2207,
+            Item::RayReceiver => 2208,
+            Item::EnergyExchanger => 2209,
+            Item::ArtificialStar => 2210,
+            Item::MiniFusionPowerStation => 2211,
+            Item::SatelliteSubstation => 2212,
+            Item::MiningMachine => 2301,
+            Item::Smelter => 2302,
+            Item::AssemblingMachineMK1 => 2303,
+            Item::AssemblingMachineMK2 => 2304,
+            Item::AssemblingMachineMK3 => 2305,
+            Item::WaterPump => 2306,
+            Item::OilExtractor => 2307,
+            Item::OilRefinery => 2308,
+            Item::ChemicalPlant => 2309,
+            Item::MiniatureParticleCollider => 2310,
+            Item::EMRailEjector => 2311,
+            Item::VerticalLaunchingSilo => 2312,
+            Item::Fractionator => 2314,
+            Item::MatrixLab => 2901,
+            Item::LogisticsDrone => 5001,
+            Item::LogisticsVessel => 5002,
+            Item::ElectromagneticMatrix => 6001,
+            Item::EnergyMatrix => 6002,
+            Item::StructureMatrix => 6003,
+            Item::InformationMatrix => 6004,
+            Item::GravityMatrix => 6005,
+            Item::UniverseMatrix => 6006,
+        }
+    }
+
+    /// Find the item with the given in-game ID.
+    pub fn from_game_id(id: u32) -> Option<Item> {
+        Item::iter().find(|item| item.game_id() == id)
+    }
+
+    /// What the item's icon is called in `site/src/img`, without the `.png`.
+    pub fn icon_key(self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Recipe {
+    /// The ID the game uses for this recipe.
+    ///
+    /// This is `None` for the mining, collecting and charging recipes, which aren't real recipes in the game.
+    pub fn game_id(self) -> Option<u32> {
+        match self {
+            Recipe::IronSmelting => Some(1),
+            Recipe::MagnetSmelting => Some(2),
+            Recipe::CopperSmelting => Some(3),
+            Recipe::StoneSmelting => Some(4),
+            Recipe::Gear => Some(5),
+            Recipe::Electromagnet => Some(6),
+            Recipe::WindTurbine => Some(7),
+            Recipe::TeslaTower => Some(8),
+            Recipe::ElectromagneticMatrix => Some(9),
+            Recipe::MatrixLab => Some(10),
+            Recipe::Prism => Some(11),
+            Recipe::PlasmaExciter => Some(12),
+            Recipe::WirelessPowerTower => Some(13),
+            Recipe::OilExtractor => Some(14),
+            Recipe::OilRefinery => Some(15),
+            Recipe::PlasmaRefining => Some(16),
+            Recipe::GraphiteSmelting => Some(17),
+            Recipe::EnergyMatrix => Some(18),
+            Recipe::HydrogenFuelRod => Some(19),
+            Recipe::Thruster => Some(20),
+            Recipe::ReinforcedThruster => Some(21),
+            Recipe::ChemicalPlant => Some(22),
+            Recipe::Plastic => Some(23),
+            Recipe::SulfuricAcidFromStone => Some(24),
+            Recipe::OrganicCrystalFromPlastic => Some(25),
+            Recipe::TitaniumCrystal => Some(26),
+            Recipe::StructureMatrix => Some(27),
+            Recipe::CasimirCrystalFromTitanium => Some(28),
+            Recipe::CasimirCrystalFromOpticalGratingCrystal => Some(29),
+            Recipe::TitaniumGlass => Some(30),
+            Recipe::GrapheneFromGraphiteAndSulfuric => Some(31),
+            Recipe::GrapheneFromFireIce => Some(32),
+            Recipe::CarbonNanotubeFromGraphene => Some(33),
+            Recipe::SiliconOreFromStone => Some(34),
+            Recipe::CarbonNanotubeFromSpiniform => Some(35),
+            Recipe::ParticleBroadband => Some(36),
+            Recipe::CrystalSiliconFromIngot => Some(37),
+            Recipe::PlaneFilter => Some(38),
+            Recipe::MiniatureParticleCollider => Some(39),
+            Recipe::DeuteriumInParticleCollider => Some(40),
+            Recipe::DeuteronFuelRod => Some(41),
+            Recipe::AnnihilationConstraintSphere => Some(42),
+            Recipe::ArtificialStar => Some(43),
+            Recipe::AntimatterFuelRod => Some(44),
+            Recipe::AssemblingMachineMK1 => Some(45),
+            Recipe::AssemblingMachineMK2 => Some(46),
+            Recipe::AssemblingMachineMK3 => Some(47),
+            Recipe::MiningMachine => Some(48),
+            Recipe::WaterPump => Some(49),
+            Recipe::CircuitBoard => Some(50),
+            Recipe::Processor => Some(51),
+            Recipe::QuantumChip => Some(52),
+            Recipe::MicrocrystallineComponent => Some(53),
+            Recipe::OrganicCrystalFromWood => Some(54),
+            Recipe::InformationMatrix => Some(55),
+            Recipe::Smelter => Some(56),
+            Recipe::GlassSmelting => Some(57),
+            Recipe::XRayCracking => Some(58),
+            Recipe::SiliconSmelting => Some(59),
+            Recipe::DiamondFromGraphite => Some(60),
+            Recipe::DiamondFromKimberlite => Some(61),
+            Recipe::CrystalSiliconFromFractal => Some(62),
+            Recipe::SteelSmelting => Some(63),
+            Recipe::ThermalPowerStation => Some(64),
+            Recipe::TitaniumSmelting => Some(65),
+            Recipe::TitaniumAlloy => Some(66),
+            Recipe::SolarPanel => Some(67),
+            Recipe::PhotonCombinerFromPrism => Some(68),
+            Recipe::PhotonCombinerFromCrystal => Some(69),
+            Recipe::SolarSail => Some(70),
+            Recipe::EMRailEjector => Some(71),
+            Recipe::RayReceiver => Some(72),
+            Recipe::SatelliteSubstation => Some(73),
+            Recipe::MiniFusionPowerStation => Some(74),
+            Recipe::UniverseMatrix => Some(75),
+            Recipe::Accumulator => Some(76),
+            Recipe::EnergyExchanger => Some(77),
+            Recipe::SpaceWarperFromLens => Some(78),
+            Recipe::SpaceWarperFromMatrix => Some(79),
+            Recipe::FrameMaterial => Some(80),
+            Recipe::DysonSphereComponent => Some(81),
+            Recipe::VerticalLaunchingSilo => Some(82),
+            Recipe::SmallCarrierRocket => Some(83),
+            Recipe::ConveyorMK2 => Some(84),
+            Recipe::SorterMK2 => Some(85),
+            Recipe::StorageMK2 => Some(86),
+            Recipe::SorterMK3 => Some(87),
+            Recipe::SorterMK1 => Some(88),
+            Recipe::ConveyorMK1 => Some(89),
+            Recipe::StorageMK1 => Some(90),
+            Recipe::StorageTank => Some(91),
+            Recipe::ConveyorMK3 => Some(92),
+            Recipe::Splitter => Some(93),
+            Recipe::DiracInversion => Some(94),
+            Recipe::PlanetaryLogisticsStation => Some(95),
+            Recipe::LogisticsDrone => Some(96),
+            Recipe::InterstellarLogisticsStation => Some(97),
+            Recipe::LogisticsVessel => Some(98),
+            Recipe::Motor => Some(99),
+            Recipe::ElectromagneticTurbine => Some(100),
+            Recipe::ParticleContainerFromEMTurbine => Some(101),
+            Recipe::ParticleContainerFromUnipolar => Some(102),
+            Recipe::GravitonLens => Some(103),
+            Recipe::GravityMatrix => Some(104),
+            Recipe::SuperMagneticRing => Some(105),
+            Recipe::StrangeMatter => Some(106),
+            Recipe::Fractionator => Some(110),
+            Recipe::OrbitCollector => Some(111),
+            Recipe::Foundation => Some(112),
+            Recipe::DeuteriumFractionation => Some(115),
+            // Not real recipes in the game
+            Recipe::IronMining
+            | Recipe::CopperMining
+            | Recipe::CoalMining
+            | Recipe::StoneMining
+            | Recipe::SiliconMining
+            | Recipe::TitaniumMining
+            | Recipe::FireIceMining
+            | Recipe::DiamondMining
+            | Recipe::FractalSiliconMining
+            | Recipe::OpticalGratingCrystalMining
+            | Recipe::OrganicCrystalMining
+            | Recipe::SpiniformStalagmiteCrystalMining
+            | Recipe::UnipolarMagnetMining
+            | Recipe::OilExtraction
+            | Recipe::CriticalPhotonReceiving
+            | Recipe::WaterPumping
+            | Recipe::SulfuricAcidPumping
+            | Recipe::GasGiantCollection
+            | Recipe::IceGiantCollection
+            | Recipe::TreeHarvesting
+            | Recipe::PlantHarvesting
+            | Recipe::ChargeAccumulator
+            | Recipe::DischargeAccumulator => None,
+        }
+    }
+
+    /// Find the recipe with the given in-game ID.
+    pub fn from_game_id(id: u32) -> Option<Recipe> {
+        Recipe::iter().find(|recipe| recipe.game_id() == Some(id))
+    }
+
+    /// Recipes use the icon of the first thing they make.
+    pub fn icon_key(self, info: &AllDSPInfo) -> Option<String> {
+        info.recipes.0[self]
+            .results
+            .first()
+            .map(|stack| stack.item.icon_key())
+    }
+}
+
+impl Technology {
+    /// The ID the game uses for this technology.
+    pub fn game_id(self) -> u32 {
+        match self {
+            Technology::DysonSphereProgram => 1,
+            Technology::Electromagnetism => 1001,
+            Technology::ElectromagneticMatrix => 1002,
+            Technology::HighEfficiencyPlasmaControl => 1101,
+            Technology::PlasmaExtractRefining => 1102,
+            Technology::EnergyMatrix => 1103,
+            Technology::HydrogenFuelRod => 1104,
+            Technology::Thruster => 1105,
+            Technology::ReinforcedThruster => 1106,
+            Technology::FluidStorageEncapsulation => 1120,
+            Technology::BasicChemicalEngineering => 1121,
+            Technology::PolymerChemicalEngineering => 1122,
+            Technology::HighStrengthCrystal => 1123,
+            Technology::StructureMatrix => 1124,
+            Technology::CasimirCrystal => 1125,
+            Technology::HighStrengthGlass => 1126,
+            Technology::XRayCracking => 1131,
+            Technology::AccelerantMK1 => 1151,
+            Technology::AccelerantMK2 => 1152,
+            Technology::AccelerantMK3 => 1153,
+            Technology::BasicAssemblingProcesses => 1201,
+            Technology::HighSpeedAssemblingProcesses => 1202,
+            Technology::QuantumPrintingTechnology => 1203,
+            Technology::SemiconductorMaterial => 1301,
+            Technology::Processor => 1302,
+            Technology::QuantumChip => 1303,
+            Technology::InformationMatrix => 1304,
+            Technology::AutomaticMetallurgy => 1401,
+            Technology::SmeltingPurification => 1402,
+            Technology::CrystalSmelting => 1403,
+            Technology::SteelSmelting => 1411,
+            Technology::EnvironmentModification => 1412,
+            Technology::TitaniumSmelting => 1413,
+            Technology::HighStrengthTitaniumAlloy => 1414,
+            Technology::SolarCollection => 1501,
+            Technology::PhotonFrequencyConversion => 1502,
+            Technology::SolarSailOrbitSystem => 1503,
+            Technology::RayReceiver => 1504,
+            Technology::PlanetaryIonosphereUtilization => 1505,
+            Technology::DysonSphereStressSystem => 1506,
+            Technology::VerticalLaunchingSilo => 1507,
+            Technology::MissionCompleted => 1508,
+            Technology::HighStrengthLightweightStructure => 1521,
+            Technology::BasicLogisticsSystem => 1601,
+            Technology::ImprovedLogisticsSystem => 1602,
+            Technology::HighEfficiencyLogisticsSystem => 1603,
+            Technology::PlanetaryLogisticsSystem => 1604,
+            Technology::InterstellarLogisticsSystem => 1605,
+            Technology::GasGiantsExplotiation => 1606,
+            Technology::ElectromagneticDrive => 1701,
+            Technology::MagneticLevitationTechnology => 1702,
+            Technology::SuperMagneticFieldGenerator => 1703,
+            Technology::MagneticParticleTrap => 1704,
+            Technology::ParticleControlTechnology => 1705,
+            Technology::MiniatureParticleCollider => 1706,
+            Technology::WaveFunctionInterference => 1707,
+            Technology::StrangeMatter => 1708,
+            Technology::GravitationalWaveRefraction => 1709,
+            Technology::GravityMatrix => 1710,
+            Technology::AppliedSuperconductor => 1711,
+            Technology::HighStrengthMaterial => 1712,
+            Technology::DiracInversionMechanism => 1721,
+            Technology::UniverseMatrix => 1722,
+            Technology::ThermalPower => 1801,
+            Technology::DeuteriumFractionation => 1802,
+            Technology::MiniFusionPowerGeneration => 1803,
+            Technology::ArtificialStar => 1804,
+            Technology::ControlledAnnihilationReaction => 1805,
+            Technology::EnergyStorage => 1811,
+            Technology::InterstellarPowerTransmission => 1812,
+            Technology::SatellitePowerDistributionSystem => 1813,
+        }
+    }
+
+    /// Find the technology with the given in-game ID.
+    pub fn from_game_id(id: u32) -> Option<Technology> {
+        Technology::iter().find(|tech| tech.game_id() == id)
+    }
+}
+
+/// Make sure every item has an icon in the given folder and no two things share an ID.
+///
+/// Returns a list of everything wrong.
+pub fn check_ids(img_dir: &Path) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for item in Item::iter() {
+        let icon = img_dir.join(format!("{}.png", item.icon_key()));
+        if !icon.is_file() {
+            problems.push(format!("{:?} has no icon at {}", item, icon.display()));
+        }
+        if Item::from_game_id(item.game_id()) != Some(item) {
+            problems.push(format!(
+                "{:?} shares ID {} with another item",
+                item,
+                item.game_id()
+            ));
+        }
+    }
+    for recipe in Recipe::iter() {
+        if let Some(id) = recipe.game_id() {
+            if Recipe::from_game_id(id) != Some(recipe) {
+                problems.push(format!("{:?} shares ID {} with another recipe", recipe, id));
+            }
+        }
+    }
+    for tech in Technology::iter() {
+        if Technology::from_game_id(tech.game_id()) != Some(tech) {
+            problems.push(format!(
+                "{:?} shares ID {} with another technology",
+                tech,
+                tech.game_id()
+            ));
+        }
+    }
+
+    problems
+}
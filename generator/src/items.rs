@@ -3,8 +3,10 @@ use std::fmt::{Debug, Formatter};
 use enum_map::Enum;
 use schemars::JsonSchema;
 use serde::Serialize;
+use strum_macros::{Display, EnumIter};
 
-#[derive(Debug, Clone, Copy, Enum, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum, EnumIter, Display, Serialize, JsonSchema)]
+#[strum(serialize_all = "title_case")]
 pub enum Item {
     IronOre,
     CopperOre,
@@ -97,18 +99,27 @@ pub enum Item {
     EnergyExchanger,
     RayReceiver,
     ArtificialStar,
+    #[strum(to_string = "Conveyor MK.I")]
     ConveyorMK1,
+    #[strum(to_string = "Conveyor MK.II")]
     ConveyorMK2,
+    #[strum(to_string = "Conveyor MK.III")]
     ConveyorMK3,
     Splitter,
+    #[strum(to_string = "Storage MK.I")]
     StorageMK1,
+    #[strum(to_string = "Storage MK.II")]
     StorageMK2,
     PlanetaryLogisticsStation,
     InterstellarLogisticsStation,
     OrbitCollector,
+    #[strum(to_string = "EM Rail Ejector")]
     EMRailEjector,
+    #[strum(to_string = "Sorter MK.I")]
     SorterMK1,
+    #[strum(to_string = "Sorter MK.II")]
     SorterMK2,
+    #[strum(to_string = "Sorter MK.III")]
     SorterMK3,
     MiningMachine,
     OilExtractor,
@@ -116,8 +127,11 @@ pub enum Item {
     MiniatureParticleCollider,
     MatrixLab,
     VerticalLaunchingSilo,
+    #[strum(to_string = "Assembling Machine MK.I")]
     AssemblingMachineMK1,
+    #[strum(to_string = "Assembling Machine MK.II")]
     AssemblingMachineMK2,
+    #[strum(to_string = "Assembling Machine MK.III")]
     AssemblingMachineMK3,
     Smelter,
     ChemicalPlant,
@@ -126,7 +140,14 @@ pub enum Item {
     StorageTank,
 }
 
-#[derive(Serialize, JsonSchema)]
+impl Item {
+    /// Is this something you place down in the world, instead of a material?
+    pub fn is_building(self) -> bool {
+        self.building().is_some()
+    }
+}
+
+#[derive(Clone, Copy, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ItemStack {
     pub item: Item,
@@ -0,0 +1,30 @@
+pub mod audit;
+pub mod bootstrap;
+pub mod build_list;
+pub mod buildings;
+pub mod calculator;
+pub mod dyson;
+pub mod encode;
+pub mod generator;
+pub mod ids;
+pub mod items;
+pub mod logistics;
+pub mod mining;
+pub mod names;
+pub mod planets;
+pub mod power;
+pub mod producers;
+pub mod query;
+pub mod recipe_graph;
+pub mod recipes;
+pub mod replicator;
+pub mod schema;
+pub mod schenum_map;
+pub mod sensitivity;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod tech_layout;
+pub mod technologies;
+pub mod upgrades;
@@ -0,0 +1,230 @@
+use crate::{
+    calculator::{output_bonus, ProductionPlan},
+    generator::AllDSPInfo,
+    items::Item,
+    recipes::Recipe,
+};
+
+/// How many meters are in an AU in-game.
+pub const METERS_PER_AU: f64 = 40_000.0;
+
+/// How fast a logistics carrier flies and how much it holds.
+#[derive(Debug, Clone, Copy)]
+pub struct Carrier {
+    /// Meters per second.
+    pub speed: f64,
+    /// Items per trip.
+    pub capacity: f64,
+}
+
+/// A kind of conveyor belt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Belt {
+    MK1,
+    MK2,
+    MK3,
+}
+
+/// A kind of sorter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sorter {
+    MK1,
+    MK2,
+    MK3,
+}
+
+impl Belt {
+    /// The item you place to build it.
+    pub fn item(self) -> Item {
+        match self {
+            Belt::MK1 => Item::ConveyorMK1,
+            Belt::MK2 => Item::ConveyorMK2,
+            Belt::MK3 => Item::ConveyorMK3,
+        }
+    }
+
+    /// Get the belt the item places, or `None` if it isn't a belt.
+    pub fn from_item(item: Item) -> Option<Belt> {
+        [Belt::MK1, Belt::MK2, Belt::MK3]
+            .iter()
+            .copied()
+            .find(|belt| belt.item() == item)
+    }
+
+    /// How many items per second it moves.
+    pub fn throughput(self) -> f64 {
+        match self {
+            Belt::MK1 => 6.0,
+            Belt::MK2 => 12.0,
+            Belt::MK3 => 30.0,
+        }
+    }
+}
+
+impl Sorter {
+    /// The item you place to build it.
+    pub fn item(self) -> Item {
+        match self {
+            Sorter::MK1 => Item::SorterMK1,
+            Sorter::MK2 => Item::SorterMK2,
+            Sorter::MK3 => Item::SorterMK3,
+        }
+    }
+
+    /// Get the sorter the item places, or `None` if it isn't a sorter.
+    pub fn from_item(item: Item) -> Option<Sorter> {
+        [Sorter::MK1, Sorter::MK2, Sorter::MK3]
+            .iter()
+            .copied()
+            .find(|sorter| sorter.item() == item)
+    }
+
+    /// How many items per second it moves when it reaches across `distance` tiles.
+    ///
+    /// A distance of 0 counts as 1.
+    pub fn throughput(self, distance: u32) -> f64 {
+        let base = match self {
+            Sorter::MK1 => 1.5,
+            Sorter::MK2 => 3.0,
+            Sorter::MK3 => 6.0,
+        };
+        base / distance.max(1) as f64
+    }
+}
+
+/// How many items per second the belt moves, or `None` if it isn't a belt.
+pub fn belt_throughput(belt: Item) -> Option<f64> {
+    Belt::from_item(belt).map(Belt::throughput)
+}
+
+/// How many items per second the sorter moves when it reaches across `distance` tiles,
+/// or `None` if it isn't a sorter.
+pub fn sorter_throughput(sorter: Item, distance: u32) -> Option<f64> {
+    Sorter::from_item(sorter).map(|sorter| sorter.throughput(distance))
+}
+
+/// Get the stats for a drone or vessel, or `None` if it isn't one, before any research.
+pub fn carrier_stats(carrier: Item) -> Option<Carrier> {
+    match carrier {
+        Item::LogisticsDrone => Some(Carrier {
+            speed: 8.0,
+            capacity: 25.0,
+        }),
+        Item::LogisticsVessel => Some(Carrier {
+            speed: 400.0,
+            capacity: 200.0,
+        }),
+        _ => None,
+    }
+}
+
+/// How many carriers each kind of station can hold.
+///
+/// Returns `(drones, vessels)`, or `None` if it isn't a station.
+pub fn station_slots(station: Item) -> Option<(u32, u32)> {
+    match station {
+        Item::PlanetaryLogisticsStation => Some((10, 0)),
+        Item::InterstellarLogisticsStation => Some((10, 10)),
+        _ => None,
+    }
+}
+
+/// What kind of belts and sorters a factory is built with.
+#[derive(Debug, Clone, Copy)]
+pub struct LogisticsModel {
+    pub belt: Belt,
+    pub sorter: Sorter,
+    /// How many tiles each sorter reaches across.
+    pub sorter_distance: u32,
+    /// How far away imports come from, in AU.
+    pub vessel_distance: f64,
+    /// The vessels flying the imports in.
+    pub vessel: Carrier,
+}
+
+/// What it takes to move one item in or out of one line of the plan.
+#[derive(Debug, Clone, Copy)]
+pub struct Flow {
+    pub item: Item,
+    /// The line moving this, or `None` if it's an import.
+    pub recipe: Option<Recipe>,
+    /// Items per minute.
+    pub per_minute: f64,
+    /// Belts of this model's kind to carry it all. This is fractional.
+    pub belts: f64,
+    /// Sorters to get it in or out of the machines, or 0 for imports.
+    ///
+    /// Every machine needs at least one, so this is always a whole number.
+    pub sorters: f64,
+    /// Vessels to fly it in. This is 0 for anything that isn't an import.
+    pub vessels: f64,
+}
+
+impl Default for LogisticsModel {
+    fn default() -> Self {
+        Self {
+            belt: Belt::MK1,
+            sorter: Sorter::MK1,
+            sorter_distance: 1,
+            vessel_distance: 1.0,
+            vessel: carrier_stats(Item::LogisticsVessel).unwrap(),
+        }
+    }
+}
+
+impl LogisticsModel {
+    /// Figure out how many belts, sorters and vessels every flow in the plan needs.
+    pub fn flows(&self, info: &AllDSPInfo, plan: &ProductionPlan) -> Vec<Flow> {
+        let belt = self.belt.throughput();
+        let sorter = self.sorter.throughput(self.sorter_distance);
+
+        let mut flows = Vec::new();
+        for line in plan.lines.iter() {
+            let entry = &info.recipes.0[line.recipe];
+            let machines = line.machines.ceil();
+            let bonus = output_bonus(entry, plan.extra_products);
+            let stacks = entry
+                .ingredients
+                .iter()
+                .map(|stack| (stack, 1.0))
+                .chain(entry.results.iter().map(|stack| (stack, bonus)));
+            for (stack, bonus) in stacks {
+                let per_minute = stack.count * bonus * line.crafts_per_minute;
+                if per_minute <= 0.0 {
+                    continue;
+                }
+                let per_machine = per_minute / 60.0 / machines;
+                flows.push(Flow {
+                    item: stack.item,
+                    recipe: Some(line.recipe),
+                    per_minute,
+                    belts: per_minute / 60.0 / belt,
+                    sorters: (per_machine / sorter).ceil() * machines,
+                    vessels: 0.0,
+                });
+            }
+        }
+
+        for (item, &per_minute) in plan.imports.iter() {
+            if per_minute <= 0.0 {
+                continue;
+            }
+            flows.push(Flow {
+                item,
+                recipe: None,
+                per_minute,
+                belts: per_minute / 60.0 / belt,
+                sorters: 0.0,
+                vessels: self.vessels_for(per_minute),
+            });
+        }
+
+        flows
+    }
+
+    /// How many vessels it takes to keep this many items per minute coming in.
+    pub fn vessels_for(&self, per_minute: f64) -> f64 {
+        let round_trip = 2.0 * self.vessel_distance * METERS_PER_AU / self.vessel.speed;
+        per_minute / 60.0 * round_trip / self.vessel.capacity
+    }
+}
@@ -1,35 +1,86 @@
-mod generator;
-mod items;
-mod producers;
-mod recipes;
-mod schenum_map;
-mod technologies;
-
-use crate::generator::AllDSPInfo;
+use generator::{
+    encode::{self, Format},
+    generator::AllDSPInfo,
+    ids,
+    schema::{self, SCHEMA_VERSION},
+};
 
 use anyhow::{anyhow, bail};
-use schemars::schema_for;
-
 use std::{
     fs,
     io::Write,
+    path::Path,
     process::{Command, Stdio},
 };
+use strum::IntoEnumIterator;
 
 fn main() -> anyhow::Result<()> {
     if !cfg!(debug_assertions) {
         bail!("You should only run this in debug mode! This way the program knows where to write the data.")
     }
 
+    println!("Checking IDs and icons...");
+    let problems = ids::check_ids(Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../site/src/img"
+    )));
+    if !problems.is_empty() {
+        bail!(
+            "Found problems with IDs and icons:\n{}",
+            problems.join("\n")
+        );
+    }
+
     println!("Generating DSP info json...");
     let info = AllDSPInfo::generate();
+    let unproducible = info.unproducible_items();
+    if !unproducible.is_empty() {
+        bail!("Nothing makes these items: {:?}", unproducible);
+    }
+    println!("Encoding each format...");
+    for format in Format::iter() {
+        let bytes = encode::encode(&info, format)?;
+        // The plain JSON ones are just dsp.json, which gets written below
+        if format.extension() == "json" {
+            println!("  {:?}: {} bytes", format, bytes.len());
+            continue;
+        }
+        let path = format!(
+            "{}/../site/src/dsp.{}",
+            env!("CARGO_MANIFEST_DIR"),
+            format.extension()
+        );
+        println!("  {:?}: {} bytes to {}", format, bytes.len(), path);
+        fs::write(path, bytes)?;
+    }
+    #[cfg(feature = "sqlite")]
+    {
+        let db_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../site/src/dsp.sqlite");
+        println!("Exporting to SQLite at {}", db_path);
+        generator::sqlite::export(&info, Path::new(db_path))?;
+    }
     let info = serde_json::to_string_pretty(&info)?;
     let output_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../site/src/dsp.json");
     println!("Planning to write {} bytes to {}", info.len(), &output_path);
     fs::write(output_path, info)?;
 
     println!("Generating schema...");
-    let schema = schema_for!(AllDSPInfo);
+    let schema = schema::schema();
+    let schema_path = schema::schema_path(SCHEMA_VERSION);
+    let published = serde_json::to_string_pretty(&schema)?;
+    if schema_path.is_file() {
+        // Once a version's out there it shouldn't change
+        if fs::read_to_string(&schema_path)? != published {
+            bail!(
+                "The schema changed, but it's still version {}! Bump SCHEMA_VERSION and add a migration.",
+                SCHEMA_VERSION
+            );
+        }
+    } else {
+        println!("Publishing schema to {}", schema_path.display());
+        fs::create_dir_all(schema_path.parent().unwrap())?;
+        fs::write(&schema_path, published)?;
+    }
 
     let schema_string = serde_json::to_string(&schema)?;
     println!("Starting json2ts...");
@@ -0,0 +1,89 @@
+use crate::{generator::RecipeEntry, items::Item, producers::Producer, recipes::Recipe};
+
+use serde::Serialize;
+
+/// How much ore one vein gives a mining machine per minute, with no research.
+pub const ORE_PER_VEIN_PER_MINUTE: f64 = 30.0;
+
+/// How mining machines and oil extractors are set up.
+///
+/// The defaults match the recipe table: one vein per mining machine,
+/// oil wells seeping 1/s, and no research.
+#[derive(Debug, Clone, Copy)]
+pub struct MiningModel {
+    /// Mining speed multiplier from research.
+    ///
+    /// Each level of Vein Utilization adds 0.1 to this; see `UpgradeLevels::apply_to_mining`.
+    pub speed: f64,
+    /// How many veins each mining machine sits on top of.
+    pub veins_per_miner: f64,
+    /// How fast oil seeps out of each well, in crude oil per second, before research.
+    pub oil_seep_rate: f64,
+}
+
+/// How many machines and veins it takes to dig up something.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MiningRequirement {
+    pub recipe: Recipe,
+    pub item: Item,
+    /// How much of the item has to come out per minute.
+    pub per_minute: f64,
+    /// Mining machines or oil extractors. This is fractional.
+    pub machines: f64,
+    /// Veins covered, or oil wells tapped. This is also fractional.
+    pub veins: f64,
+}
+
+impl Default for MiningModel {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            veins_per_miner: 1.0,
+            oil_seep_rate: 1.0,
+        }
+    }
+}
+
+impl MiningModel {
+    /// How much ore one mining machine puts out per minute.
+    pub fn miner_output(&self) -> f64 {
+        self.veins_per_miner * ORE_PER_VEIN_PER_MINUTE * self.speed
+    }
+
+    /// How much crude oil one oil extractor puts out per minute.
+    pub fn extractor_output(&self) -> f64 {
+        self.oil_seep_rate * 60.0 * self.speed
+    }
+
+    /// Work out how many machines and veins it takes to run a mining recipe
+    /// this many times a minute.
+    ///
+    /// Returns `None` if the recipe isn't made in a mining machine or an oil extractor.
+    pub fn requirement(
+        &self,
+        entry: &RecipeEntry,
+        crafts_per_minute: f64,
+    ) -> Option<MiningRequirement> {
+        let result = entry.results.first()?;
+        let per_minute = result.count * crafts_per_minute;
+        let (machines, veins) = match entry.made_in {
+            Producer::MiningMachine => {
+                let machines = per_minute / self.miner_output();
+                (machines, machines * self.veins_per_miner)
+            }
+            Producer::OilExtractor => {
+                // One extractor per well
+                let machines = per_minute / self.extractor_output();
+                (machines, machines)
+            }
+            _ => return None,
+        };
+        Some(MiningRequirement {
+            recipe: entry.recipe,
+            item: result.item,
+            per_minute,
+            machines,
+            veins,
+        })
+    }
+}
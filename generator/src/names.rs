@@ -0,0 +1,247 @@
+//! Turning whatever people type into items, recipes, technologies and producers.
+//!
+//! Names are matched ignoring case, spaces and punctuation, so `em rail ejector`,
+//! `EMRailEjector` and `Em-Rail-Ejector` are all the same. Things also have aliases,
+//! like `blue science` for the electromagnetic matrix or `ILS` for the interstellar logistics station.
+
+use crate::{
+    items::Item, producers::Producer, recipes::Recipe, schenum_map::SchemingEnumMap,
+    technologies::Technology,
+};
+
+use enum_map::EnumMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+
+/// Something with a name people might type in.
+pub trait Named: Copy + Debug + Display + IntoEnumIterator {
+    /// What to call this kind of thing in error messages.
+    const KIND: &'static str;
+
+    /// Other things people call it, besides its variant name and display name.
+    fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Every name this goes by, normalized.
+    fn names(self) -> Vec<String> {
+        let mut names = vec![
+            normalize(&format!("{:?}", self)),
+            normalize(&self.to_string()),
+        ];
+        names.extend(self.aliases().iter().map(|alias| normalize(alias)));
+        names
+    }
+}
+
+/// Couldn't find anything by that name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownName {
+    pub text: String,
+    pub kind: &'static str,
+    /// The closest names, best first.
+    pub suggestions: Vec<String>,
+}
+
+impl Display for UnknownName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` isn't a {}", self.text, self.kind)?;
+        if !self.suggestions.is_empty() {
+            write!(f, "; did you mean {}?", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for UnknownName {}
+
+/// How many suggestions to give when nothing matches.
+const SUGGESTIONS: usize = 5;
+
+/// Lowercase and strip out everything that isn't a letter or number.
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Find the thing with exactly this name or alias, give or take case and spacing.
+pub fn resolve<T: Named>(text: &str) -> Result<T, UnknownName> {
+    let wanted = normalize(text);
+    T::iter()
+        .find(|it| it.names().contains(&wanted))
+        .ok_or_else(|| UnknownName {
+            text: text.to_string(),
+            kind: T::KIND,
+            suggestions: suggest::<T>(text)
+                .into_iter()
+                .take(SUGGESTIONS)
+                .map(|it| it.to_string())
+                .collect(),
+        })
+}
+
+/// Rank everything by how close its names are to the text, closest first.
+///
+/// Names that start with or contain the text come first, then everything else
+/// by how many letters you'd have to change.
+pub fn suggest<T: Named>(text: &str) -> Vec<T> {
+    let wanted = normalize(text);
+    let mut scored: Vec<_> = T::iter()
+        .map(|it| {
+            let score = it
+                .names()
+                .iter()
+                .map(|name| {
+                    if name.starts_with(&wanted) {
+                        (0, name.len())
+                    } else if name.contains(&wanted) {
+                        (1, name.len())
+                    } else {
+                        (2, edit_distance(name, &wanted))
+                    }
+                })
+                .min()
+                .unwrap();
+            (score, it)
+        })
+        .collect();
+    // Stable, so ties stay in declaration order
+    scored.sort_by_key(|(score, _)| *score);
+    scored.into_iter().map(|(_, it)| it).collect()
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut row: Vec<_> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = diagonal + (ca != cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl Named for Item {
+    const KIND: &'static str = "item";
+
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Item::ElectromagneticMatrix => {
+                &["em matrix", "blue science", "blue matrix", "blue cube"]
+            }
+            Item::EnergyMatrix => &["red science", "red matrix", "red cube"],
+            Item::StructureMatrix => &["yellow science", "yellow matrix", "yellow cube"],
+            Item::InformationMatrix => &["purple science", "purple matrix", "purple cube"],
+            Item::GravityMatrix => &["green science", "green matrix", "green cube"],
+            Item::UniverseMatrix => &["white science", "white matrix", "white cube"],
+            Item::InterstellarLogisticsStation => &["ils"],
+            Item::PlanetaryLogisticsStation => &["pls"],
+            Item::EMRailEjector => &["ejector", "rail ejector"],
+            Item::ElectromagneticTurbine => &["em turbine"],
+            Item::Electromagnet => &["magnetic coil", "coil"],
+            Item::MiniatureParticleCollider => &["particle collider", "collider"],
+            Item::VerticalLaunchingSilo => &["silo"],
+            Item::SmallCarrierRocket => &["rocket"],
+            Item::AssemblingMachineMK1 => &["assembler mk1", "assembler 1"],
+            Item::AssemblingMachineMK2 => &["assembler mk2", "assembler 2"],
+            Item::AssemblingMachineMK3 => &["assembler mk3", "assembler 3"],
+            Item::Smelter => &["arc smelter"],
+            Item::CircuitBoard => &["circuit", "green circuit"],
+            Item::HydrogenFuelRod => &["h rod"],
+            Item::DeuteronFuelRod => &["d rod"],
+            Item::AntimatterFuelRod => &["am rod"],
+            _ => &[],
+        }
+    }
+}
+
+impl Named for Recipe {
+    const KIND: &'static str = "recipe";
+
+    /// Recipes named after an item go by that item's aliases too.
+    fn aliases(self) -> &'static [&'static str] {
+        let name = format!("{:?}", self);
+        Item::iter()
+            .find(|item| format!("{:?}", item) == name)
+            .map_or(&[], |item| item.aliases())
+    }
+}
+
+impl Named for Technology {
+    const KIND: &'static str = "technology";
+
+    /// Techs named after an item go by that item's aliases too.
+    fn aliases(self) -> &'static [&'static str] {
+        let name = format!("{:?}", self);
+        Item::iter()
+            .find(|item| format!("{:?}", item) == name)
+            .map_or(&[], |item| item.aliases())
+    }
+}
+
+impl Named for Producer {
+    const KIND: &'static str = "producer";
+
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Producer::AssemblingMachine => &["assembler"],
+            Producer::Smelter => &["arc smelter"],
+            Producer::MatrixLab => &["lab"],
+            Producer::MiniatureParticleCollider => &["particle collider", "collider"],
+            Producer::RayReceiver => &["receiver"],
+            Producer::Mecha => &["icarus", "hand"],
+            _ => &[],
+        }
+    }
+}
+
+macro_rules! from_str_by_name {
+    ($($ty:ty),*) => {
+        $(
+            impl FromStr for $ty {
+                type Err = UnknownName;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    resolve(s)
+                }
+            }
+        )*
+    };
+}
+
+from_str_by_name!(Item, Recipe, Technology, Producer);
+
+/// What the game calls everything, for showing to people.
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisplayNames {
+    pub items: SchemingEnumMap<Item, String>,
+    pub recipes: SchemingEnumMap<Recipe, String>,
+    pub technologies: SchemingEnumMap<Technology, String>,
+    pub producers: SchemingEnumMap<Producer, String>,
+}
+
+impl DisplayNames {
+    pub fn generate() -> Self {
+        Self {
+            items: EnumMap::from(|item: Item| item.to_string()).into(),
+            recipes: EnumMap::from(|recipe: Recipe| recipe.to_string()).into(),
+            technologies: EnumMap::from(|tech: Technology| tech.to_string()).into(),
+            producers: EnumMap::from(|producer: Producer| producer.to_string()).into(),
+        }
+    }
+}
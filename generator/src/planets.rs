@@ -0,0 +1,304 @@
+//! Splitting a production plan across planets.
+//!
+//! Each planet has its own raw resources, power and room for machines. Lines go where the
+//! most of their ingredients are already being made, so as little as possible has to fly
+//! between planets, and whatever still does is reported as flows for ILSes to carry.
+//!
+//! This is greedy, not optimal: it places lines from raw materials up and never moves
+//! one once it's placed.
+
+use crate::{
+    calculator::{output_bonus, PlanLine, ProductionPlan},
+    generator::AllDSPInfo,
+    items::Item,
+    logistics::LogisticsModel,
+    power::work_power,
+    recipes::Recipe,
+};
+
+use anyhow::bail;
+use enum_map::EnumMap;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+/// Anything smaller than this is just floating-point noise.
+const EPSILON: f64 = 1e-9;
+
+/// Somewhere to build.
+#[derive(Debug, Clone)]
+pub struct Planet {
+    pub name: String,
+    /// Raw items per minute the planet can supply, from veins, oceans, gas and so on.
+    ///
+    /// Lines with no ingredients, like mining, can only go where there's enough of what they make.
+    pub resources: EnumMap<Item, f64>,
+    /// Watts the planet has to spare for machines.
+    pub power: f64,
+    /// How many machines there's room for.
+    pub max_machines: f64,
+}
+
+/// Which planet does what.
+#[derive(Debug, Clone, Serialize)]
+pub struct Allocation {
+    /// In the same order as the planets passed in.
+    pub planets: Vec<PlanetPlan>,
+    /// Everything ILSes have to carry between planets.
+    pub flows: Vec<InterplanetFlow>,
+}
+
+/// What one planet has to build.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanetPlan {
+    pub name: String,
+    /// This planet's share of each line of the plan, in the order of `Recipe`.
+    pub lines: Vec<PlanLine>,
+    /// This is fractional, like `PlanLine::machines`.
+    pub machines: f64,
+    /// Watts.
+    pub power: f64,
+}
+
+/// Items that have to fly from one planet to another.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct InterplanetFlow {
+    pub item: Item,
+    /// The index of the planet sending it, or `None` if none of them have it
+    /// and it has to come from somewhere else.
+    pub from: Option<usize>,
+    pub to: usize,
+    pub per_minute: f64,
+    /// Vessels to carry it, going by the logistics model.
+    pub vessels: f64,
+}
+
+impl Planet {
+    /// A planet with no resources, but unlimited power and room.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            resources: EnumMap::from(|_| 0.0),
+            power: f64::INFINITY,
+            max_machines: f64::INFINITY,
+        }
+    }
+}
+
+/// Where everything's up to on one planet while allocating.
+struct PlanetState {
+    /// How much of each item is made here minus how much is used here, per minute.
+    balance: EnumMap<Item, f64>,
+    resources: EnumMap<Item, f64>,
+    shares: EnumMap<Recipe, f64>,
+    machines: f64,
+    power: f64,
+}
+
+impl Allocation {
+    /// How many items per minute fly between planets in total.
+    pub fn interstellar_per_minute(&self) -> f64 {
+        self.flows
+            .iter()
+            .filter(|flow| flow.from.is_some())
+            .map(|flow| flow.per_minute)
+            .sum()
+    }
+}
+
+/// Split the plan across the planets.
+///
+/// This is a greedy heuristic, not an optimal split: each line goes wherever the most of
+/// its ingredients are at the time, and never moves after that.
+///
+/// Fails if some line doesn't fit anywhere, either for lack of room or power,
+/// or because no planet has the resources for a mining line.
+pub fn allocate(
+    info: &AllDSPInfo,
+    plan: &ProductionPlan,
+    planets: &[Planet],
+    logistics: &LogisticsModel,
+) -> anyhow::Result<Allocation> {
+    let mut states: Vec<_> = planets
+        .iter()
+        .map(|planet| PlanetState {
+            balance: EnumMap::from(|_| 0.0),
+            resources: planet.resources,
+            shares: EnumMap::from(|_| 0.0),
+            machines: 0.0,
+            power: 0.0,
+        })
+        .collect();
+
+    // Imports come out of whichever planets have them, biggest supply first
+    for (item, &amount) in plan.imports.iter() {
+        let mut left = amount;
+        while left > EPSILON {
+            let best = (0..states.len())
+                .filter(|&idx| states[idx].resources[item] > EPSILON)
+                .max_by(|&a, &b| states[a].resources[item].total_cmp(&states[b].resources[item]));
+            let idx = match best {
+                Some(idx) => idx,
+                // The rest comes from off the map entirely
+                None => break,
+            };
+            let taken = left.min(states[idx].resources[item]);
+            states[idx].resources[item] -= taken;
+            states[idx].balance[item] += taken;
+            left -= taken;
+        }
+    }
+
+    for line in lines_in_order(info, plan) {
+        // Nothing to place, and the mining math below would divide by it
+        if line.crafts_per_minute <= EPSILON {
+            continue;
+        }
+        let entry = &info.recipes.0[line.recipe];
+        let power_per_machine = work_power(line.made_in);
+        let mut left = 1.0;
+        while left > EPSILON {
+            // Work out how much of the line each planet could take, and how much
+            // of what it needs is already there
+            let mut best: Option<(usize, f64, f64)> = None;
+            for (idx, (planet, state)) in planets.iter().zip(states.iter()).enumerate() {
+                let mut fits = left;
+                if line.machines > EPSILON {
+                    fits = fits.min((planet.max_machines - state.machines) / line.machines);
+                    if power_per_machine > 0.0 {
+                        fits = fits.min(
+                            (planet.power - state.power) / (line.machines * power_per_machine),
+                        );
+                    }
+                }
+                let score = if entry.ingredients.is_empty() {
+                    for result in entry.results.iter() {
+                        let wanted = result.count * line.crafts_per_minute;
+                        fits = fits.min(state.resources[result.item] / wanted);
+                    }
+                    fits
+                } else {
+                    entry
+                        .ingredients
+                        .iter()
+                        .map(|stack| {
+                            let wanted = stack.count * line.crafts_per_minute * fits;
+                            state.balance[stack.item].max(0.0).min(wanted)
+                        })
+                        .sum()
+                };
+                if fits <= EPSILON {
+                    continue;
+                }
+                if best.is_none_or(|(_, _, best_score)| score > best_score + EPSILON) {
+                    best = Some((idx, fits, score));
+                }
+            }
+
+            let (idx, fits) = match best {
+                Some((idx, fits, _)) => (idx, fits),
+                None => bail!(
+                    "There's nowhere left to put {:.2} machines of {}",
+                    line.machines * left,
+                    line.recipe
+                ),
+            };
+            let state = &mut states[idx];
+            let crafts = line.crafts_per_minute * fits;
+            for stack in entry.results.iter() {
+                state.balance[stack.item] +=
+                    stack.count * output_bonus(entry, plan.extra_products) * crafts;
+                if entry.ingredients.is_empty() {
+                    state.resources[stack.item] -= stack.count * crafts;
+                }
+            }
+            for stack in entry.ingredients.iter() {
+                state.balance[stack.item] -= stack.count * crafts;
+            }
+            state.shares[line.recipe] += fits;
+            state.machines += line.machines * fits;
+            state.power += line.machines * fits * power_per_machine;
+            left -= fits;
+        }
+    }
+
+    // Send surpluses to wherever's short, biggest surplus first
+    let mut flows = Vec::new();
+    for item in Item::iter() {
+        for to in 0..states.len() {
+            while states[to].balance[item] < -EPSILON {
+                let wanted = -states[to].balance[item];
+                let from = (0..states.len())
+                    .filter(|&idx| states[idx].balance[item] > EPSILON)
+                    .max_by(|&a, &b| states[a].balance[item].total_cmp(&states[b].balance[item]));
+                let per_minute = match from {
+                    Some(from) => wanted.min(states[from].balance[item]),
+                    None => wanted,
+                };
+                if let Some(from) = from {
+                    states[from].balance[item] -= per_minute;
+                }
+                states[to].balance[item] += per_minute;
+                flows.push(InterplanetFlow {
+                    item,
+                    from,
+                    to,
+                    per_minute,
+                    vessels: logistics.vessels_for(per_minute),
+                });
+            }
+        }
+    }
+
+    let planets = planets
+        .iter()
+        .zip(states.iter())
+        .map(|(planet, state)| PlanetPlan {
+            name: planet.name.clone(),
+            lines: plan
+                .lines
+                .iter()
+                .filter(|line| state.shares[line.recipe] > EPSILON)
+                .map(|line| PlanLine {
+                    crafts_per_minute: line.crafts_per_minute * state.shares[line.recipe],
+                    machines: line.machines * state.shares[line.recipe],
+                    ..*line
+                })
+                .collect(),
+            machines: state.machines,
+            power: state.power,
+        })
+        .collect();
+
+    Ok(Allocation { planets, flows })
+}
+
+/// The plan's lines, with each one after the lines making its ingredients,
+/// as far as loops allow.
+fn lines_in_order(info: &AllDSPInfo, plan: &ProductionPlan) -> Vec<PlanLine> {
+    let mut level: EnumMap<Recipe, usize> = EnumMap::from(|_| 0);
+    // Loops would go up forever, so stop after enough passes for the longest chain
+    for _ in 0..plan.lines.len() {
+        for line in plan.lines.iter() {
+            let entry = &info.recipes.0[line.recipe];
+            level[line.recipe] = plan
+                .lines
+                .iter()
+                .filter(|other| other.recipe != line.recipe)
+                .filter(|other| {
+                    info.recipes.0[other.recipe].results.iter().any(|result| {
+                        entry
+                            .ingredients
+                            .iter()
+                            .any(|stack| stack.item == result.item)
+                    })
+                })
+                .map(|other| level[other.recipe] + 1)
+                .max()
+                .unwrap_or(0)
+                .min(plan.lines.len());
+        }
+    }
+    let mut lines = plan.lines.clone();
+    lines.sort_by_key(|line| level[line.recipe]);
+    lines
+}
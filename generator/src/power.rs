@@ -0,0 +1,155 @@
+use crate::{
+    calculator::{Calculator, ProductionPlan},
+    items::{Item, ItemStack},
+    producers::Producer,
+};
+
+/// How much energy a full accumulator holds, in joules.
+pub const ACCUMULATOR_CAPACITY: f64 = 180e6;
+/// How fast an energy exchanger charges or drains accumulators, in watts.
+pub const EXCHANGER_POWER: f64 = 45e6;
+
+/// How much energy the item gives when burned in a generator, in joules,
+/// or `None` if it isn't fuel.
+pub fn fuel_energy(item: Item) -> Option<f64> {
+    match item {
+        Item::CoalOre => Some(2.7e6),
+        Item::CrudeOil => Some(4.05e6),
+        Item::Hydrogen => Some(9e6),
+        Item::RefinedOil => Some(4.4e6),
+        Item::FireIce => Some(4.8e6),
+        Item::Log => Some(2e6),
+        Item::PlantFuel => Some(5e6),
+        Item::EnergeticGraphite => Some(6.3e6),
+        Item::HydrogenFuelRod => Some(54e6),
+        Item::DeuteronFuelRod => Some(600e6),
+        Item::AntimatterFuelRod => Some(7.5e9),
+        _ => None,
+    }
+}
+
+/// The most power the generator can put out, in watts,
+/// or `None` if it isn't a generator that burns fuel.
+pub fn generator_output(generator: Item) -> Option<f64> {
+    match generator {
+        Item::ThermalPowerStation => Some(2.16e6),
+        Item::MiniFusionPowerStation => Some(15e6),
+        Item::ArtificialStar => Some(72e6),
+        _ => None,
+    }
+}
+
+/// How much power one of the producer's machines uses while it's working, in watts.
+///
+/// Assembling machines are MK.II, same as the calculator's default speeds.
+pub fn work_power(producer: Producer) -> f64 {
+    match producer {
+        Producer::AssemblingMachine => 480e3,
+        Producer::Smelter => 360e3,
+        Producer::OilRefinery => 960e3,
+        Producer::ChemicalPlant => 720e3,
+        Producer::Fractionator => 720e3,
+        Producer::MatrixLab => 480e3,
+        Producer::MiniatureParticleCollider => 12e6,
+        Producer::MiningMachine => 420e3,
+        Producer::OilExtractor => 840e3,
+        Producer::WaterPump => 300e3,
+        // These make power or don't need any
+        Producer::RayReceiver
+        | Producer::OrbitCollector
+        | Producer::EnergyExchanger
+        | Producer::Mecha => 0.0,
+    }
+}
+
+/// All the fuels the generator can burn.
+pub fn compatible_fuels(generator: Item) -> &'static [Item] {
+    match generator {
+        Item::ThermalPowerStation => &[
+            Item::CoalOre,
+            Item::CrudeOil,
+            Item::Hydrogen,
+            Item::RefinedOil,
+            Item::FireIce,
+            Item::Log,
+            Item::PlantFuel,
+            Item::EnergeticGraphite,
+            Item::HydrogenFuelRod,
+        ],
+        Item::MiniFusionPowerStation => &[Item::DeuteronFuelRod],
+        Item::ArtificialStar => &[Item::AntimatterFuelRod],
+        _ => &[],
+    }
+}
+
+/// What it takes to keep some generators running.
+#[derive(Debug)]
+pub struct FuelPlan {
+    pub generator: Item,
+    pub fuel: Item,
+    /// How much power is wanted, in MW.
+    pub megawatts: f64,
+    /// This is fractional.
+    pub generators: f64,
+    pub fuel_per_minute: f64,
+    /// Everything it takes to make the fuel.
+    pub production: ProductionPlan,
+}
+
+/// How much fuel per minute it takes to get `megawatts` out of generators burning it.
+///
+/// Returns `None` if the generator can't burn that fuel.
+pub fn fuel_per_minute(generator: Item, fuel: Item, megawatts: f64) -> Option<f64> {
+    if !compatible_fuels(generator).contains(&fuel) {
+        return None;
+    }
+    Some(megawatts * 1e6 * 60.0 / fuel_energy(fuel)?)
+}
+
+/// Work out how many generators and how much fuel it takes to get `megawatts` of power,
+/// and run the fuel through the calculator.
+///
+/// Returns `None` if the generator can't burn that fuel.
+pub fn plan_fuel(
+    calculator: &Calculator,
+    generator: Item,
+    fuel: Item,
+    megawatts: f64,
+) -> Option<FuelPlan> {
+    let fuel_per_minute = fuel_per_minute(generator, fuel, megawatts)?;
+    Some(FuelPlan {
+        generator,
+        fuel,
+        megawatts,
+        generators: megawatts * 1e6 / generator_output(generator)?,
+        fuel_per_minute,
+        production: calculator.plan(&[ItemStack {
+            item: fuel,
+            count: fuel_per_minute,
+        }]),
+    })
+}
+
+/// What it takes to move power around in accumulators.
+#[derive(Debug, Clone, Copy)]
+pub struct PowerTransport {
+    /// How much power is moved, in MW.
+    pub megawatts: f64,
+    /// Accumulators charged on one end and drained on the other, per minute.
+    pub accumulators_per_minute: f64,
+    /// Energy exchangers needed on each end. This is fractional.
+    pub exchangers: f64,
+}
+
+/// Work out how many accumulators and exchangers it takes to move `megawatts` of power.
+///
+/// To plan it like any other item, ask the calculator for `accumulators_per_minute`
+/// `FullAccumulator`s and mark `Accumulator` as raw, since the empties come back.
+pub fn power_transport(megawatts: f64) -> PowerTransport {
+    let watts = megawatts * 1e6;
+    PowerTransport {
+        megawatts,
+        accumulators_per_minute: watts * 60.0 / ACCUMULATOR_CAPACITY,
+        exchangers: watts / EXCHANGER_POWER,
+    }
+}
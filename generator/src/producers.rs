@@ -1,8 +1,11 @@
+use enum_map::Enum;
 use schemars::JsonSchema;
 use serde::Serialize;
+use strum_macros::{Display, EnumIter};
 
 /// Anything that makes items.
-#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum, EnumIter, Display, Serialize, JsonSchema)]
+#[strum(serialize_all = "title_case")]
 #[allow(dead_code)]
 pub enum Producer {
     AssemblingMachine,
@@ -18,4 +21,7 @@ pub enum Producer {
     RayReceiver,
     WaterPump,
     OrbitCollector,
+    EnergyExchanger,
+    /// Icarus, doing it by hand.
+    Mecha,
 }
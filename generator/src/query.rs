@@ -0,0 +1,197 @@
+//! A little search language for recipes.
+//!
+//! A query is a bunch of terms separated by spaces, and a recipe has to match all of them.
+//! Put a `-` in front of a term to flip it.
+//!
+//! - `uses:Item`, `produces:Item`: the recipe takes or makes the item.
+//!   Names can be written any way `names::resolve` understands, but no spaces, so `uses:em_matrix`.
+//! - `made_in:Producer`
+//! - `time:4`, `time>4`, `time<=2.5`: how many seconds it takes.
+//! - `unlocked_by:Tech` is exactly that tech. `unlocked_by<=Tech` is that tech or anything
+//!   you need before it, and `unlocked_by>=Tech` is that tech or anything after it.
+//!   `<` and `>` leave out the tech itself.
+//! - `handcraftable:true`, `handcraftable:false`
+//! - `name:text`, or just `text` on its own: the recipe's name has that in it.
+//!
+//! Example: `uses:TitaniumIngot made_in:ChemicalPlant time>4`
+
+use crate::{
+    generator::AllDSPInfo, items::Item, names::resolve, producers::Producer, recipes::Recipe,
+    technologies::Technology,
+};
+
+use anyhow::{anyhow, bail};
+use strum::IntoEnumIterator;
+
+use std::str::FromStr;
+
+/// A parsed query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub terms: Vec<Term>,
+}
+
+/// One thing a recipe has to match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    /// If this is set, the recipe has to *not* match the filter.
+    pub negated: bool,
+    pub filter: Filter,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Uses(Item),
+    Produces(Item),
+    MadeIn(Producer),
+    Time(Comparison, f32),
+    UnlockedBy(Comparison, Technology),
+    Handcraftable(bool),
+    /// Lowercased already.
+    Name(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Does `ordering` (the recipe's value compared to the query's) satisfy this?
+    fn accepts(self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            Comparison::Equal => ordering == Equal,
+            Comparison::Less => ordering == Less,
+            Comparison::LessOrEqual => ordering != Greater,
+            Comparison::Greater => ordering == Greater,
+            Comparison::GreaterOrEqual => ordering != Less,
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = s
+            .split_whitespace()
+            .map(|term| term.parse())
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { terms })
+    }
+}
+
+impl FromStr for Term {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negated, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        // Longest operators first so `<=` doesn't get read as `<`
+        let split = ["<=", ">=", ":", "<", ">", "="]
+            .iter()
+            .filter_map(|op| s.find(op).map(|idx| (idx, *op)))
+            .min_by_key(|(idx, op)| (*idx, usize::MAX - op.len()));
+        let (key, op, value) = match split {
+            Some((idx, op)) => (&s[..idx], op, &s[idx + op.len()..]),
+            None => {
+                return Ok(Self {
+                    negated,
+                    filter: Filter::Name(s.to_lowercase()),
+                })
+            }
+        };
+        let cmp = match op {
+            ":" | "=" => Comparison::Equal,
+            "<" => Comparison::Less,
+            "<=" => Comparison::LessOrEqual,
+            ">" => Comparison::Greater,
+            ">=" => Comparison::GreaterOrEqual,
+            _ => unreachable!(),
+        };
+        let only_equal = |filter| {
+            if cmp == Comparison::Equal {
+                Ok(filter)
+            } else {
+                Err(anyhow!("`{}` can only be used with `:`", key))
+            }
+        };
+
+        let filter = match key.to_lowercase().as_str() {
+            "uses" => only_equal(Filter::Uses(resolve(value)?))?,
+            "produces" => only_equal(Filter::Produces(resolve(value)?))?,
+            "made_in" => only_equal(Filter::MadeIn(resolve(value)?))?,
+            "time" => Filter::Time(
+                cmp,
+                value
+                    .parse()
+                    .map_err(|_| anyhow!("`{}` isn't a number of seconds", value))?,
+            ),
+            "unlocked_by" => Filter::UnlockedBy(cmp, resolve(value)?),
+            "handcraftable" => only_equal(Filter::Handcraftable(
+                value
+                    .parse()
+                    .map_err(|_| anyhow!("`{}` should be true or false", value))?,
+            ))?,
+            "name" => only_equal(Filter::Name(value.to_lowercase()))?,
+            _ => bail!("I don't know how to search by `{}`", key),
+        };
+        Ok(Self { negated, filter })
+    }
+}
+
+impl Query {
+    /// Does the recipe match every term?
+    pub fn matches(&self, info: &AllDSPInfo, recipe: Recipe) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.filter.matches(info, recipe) != term.negated)
+    }
+
+    /// Get all the recipes that match, in the order of `Recipe`.
+    pub fn run(&self, info: &AllDSPInfo) -> Vec<Recipe> {
+        Recipe::iter()
+            .filter(|&recipe| self.matches(info, recipe))
+            .collect()
+    }
+}
+
+impl Filter {
+    pub fn matches(&self, info: &AllDSPInfo, recipe: Recipe) -> bool {
+        let entry = &info.recipes.0[recipe];
+        match self {
+            Filter::Uses(item) => info.consumption_methods.0[*item].contains(&recipe),
+            Filter::Produces(item) => info.production_methods.0[*item].contains(&recipe),
+            Filter::MadeIn(producer) => entry.made_in == *producer,
+            Filter::Time(cmp, time) => entry
+                .time
+                .partial_cmp(time)
+                .is_some_and(|ordering| cmp.accepts(ordering)),
+            Filter::UnlockedBy(cmp, tech) => {
+                use std::cmp::Ordering::*;
+                let unlock = entry.unlocked_by;
+                // Techs only compare if one leads to the other
+                let ordering = if unlock == *tech {
+                    Some(Equal)
+                } else if info.tech_tree.0[*tech].all_prereqs.contains(&unlock) {
+                    Some(Less)
+                } else if info.tech_tree.0[*tech].all_postreqs.contains(&unlock) {
+                    Some(Greater)
+                } else {
+                    None
+                };
+                ordering.is_some_and(|ordering| cmp.accepts(ordering))
+            }
+            Filter::Handcraftable(handcraftable) => entry.handcraftable == *handcraftable,
+            Filter::Name(text) => format!("{:?}", recipe).to_lowercase().contains(text),
+        }
+    }
+}
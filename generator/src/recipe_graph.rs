@@ -0,0 +1,244 @@
+use crate::{generator::AllDSPInfo, items::Item, producers::Producer, recipes::Recipe};
+
+use enum_map::EnumMap;
+
+use std::fmt::Write;
+
+/// What goes into a recipe graph and how it looks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphOptions {
+    /// Only show what it takes to make this item.
+    pub ancestors_of: Option<Item>,
+    /// Squash every recipe that makes a building into a single node,
+    /// and leave out the buildings themselves.
+    pub collapse_buildings: bool,
+    /// Color each recipe by what it's made in.
+    pub color_by_producer: bool,
+}
+
+/// A node in the graph. This is an item or a recipe, or all the building recipes squashed together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    Item(Item),
+    Recipe(Recipe),
+    /// Where all the building recipes go when they're collapsed.
+    Buildings,
+}
+
+/// The bipartite graph of items going into recipes and recipes making items.
+pub struct RecipeGraph {
+    items: Vec<Item>,
+    recipes: Vec<(Recipe, Producer)>,
+    has_buildings: bool,
+    /// `(from, to, count)`
+    edges: Vec<(Node, Node, f64)>,
+    options: GraphOptions,
+}
+
+impl Node {
+    fn id(self) -> String {
+        match self {
+            Node::Item(item) => format!("item_{:?}", item),
+            Node::Recipe(recipe) => format!("recipe_{:?}", recipe),
+            Node::Buildings => "buildings".to_string(),
+        }
+    }
+}
+
+impl RecipeGraph {
+    pub fn new(info: &AllDSPInfo, options: GraphOptions) -> Self {
+        let recipes = &info.recipes.0;
+        // Charging and emptying accumulators happens in a placed exchanger, so those
+        // don't count even though accumulators are buildings
+        let is_building_recipe = |recipe: Recipe| {
+            let entry = &recipes[recipe];
+            entry.made_in == Producer::AssemblingMachine
+                && !entry.results.is_empty()
+                && entry.results.iter().all(|stack| stack.item.is_building())
+        };
+
+        let mut keep_recipe: EnumMap<Recipe, bool> = EnumMap::from(|_| false);
+        match options.ancestors_of {
+            Some(target) => {
+                let mut seen: EnumMap<Item, bool> = EnumMap::from(|_| false);
+                let mut todo = vec![target];
+                while let Some(item) = todo.pop() {
+                    if seen[item] {
+                        continue;
+                    }
+                    seen[item] = true;
+                    for &recipe in info.production_methods.0[item].iter() {
+                        keep_recipe[recipe] = true;
+                        todo.extend(recipes[recipe].ingredients.iter().map(|stack| stack.item));
+                    }
+                }
+            }
+            None => keep_recipe = EnumMap::from(|_| true),
+        }
+
+        let mut keep_item: EnumMap<Item, bool> = EnumMap::from(|_| false);
+        let mut kept_recipes = Vec::new();
+        let mut has_buildings = false;
+        let mut edges = Vec::new();
+        for (recipe, entry) in recipes.iter() {
+            if !keep_recipe[recipe] {
+                continue;
+            }
+            let node = if options.collapse_buildings && is_building_recipe(recipe) {
+                has_buildings = true;
+                Node::Buildings
+            } else {
+                kept_recipes.push((recipe, entry.made_in));
+                Node::Recipe(recipe)
+            };
+
+            for stack in entry.ingredients.iter() {
+                // Upgrading a building uses up the old one, which is left out too
+                if node == Node::Buildings && stack.item.is_building() {
+                    continue;
+                }
+                keep_item[stack.item] = true;
+                let from = Node::Item(stack.item);
+                // Only one edge per item into the collapsed node
+                let duplicate = node == Node::Buildings
+                    && edges
+                        .iter()
+                        .any(|&(other, to, _)| other == from && to == Node::Buildings);
+                if !duplicate {
+                    edges.push((from, node, stack.count));
+                }
+            }
+            if node != Node::Buildings {
+                for stack in entry.results.iter() {
+                    keep_item[stack.item] = true;
+                    edges.push((node, Node::Item(stack.item), stack.count));
+                }
+            }
+        }
+
+        Self {
+            items: keep_item
+                .iter()
+                .filter(|(_, &keep)| keep)
+                .map(|(item, _)| item)
+                .collect(),
+            recipes: kept_recipes,
+            has_buildings,
+            edges,
+            options,
+        }
+    }
+
+    /// Render the graph in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph recipes {\n    rankdir=LR;\n");
+        for &item in self.items.iter() {
+            writeln!(
+                out,
+                "    {} [label=\"{:?}\", shape=ellipse];",
+                Node::Item(item).id(),
+                item
+            )
+            .unwrap();
+        }
+        for &(recipe, producer) in self.recipes.iter() {
+            let color = if self.options.color_by_producer {
+                format!(", style=filled, fillcolor=\"{}\"", producer_color(producer))
+            } else {
+                String::new()
+            };
+            writeln!(
+                out,
+                "    {} [label=\"{:?}\", shape=box{}];",
+                Node::Recipe(recipe).id(),
+                recipe,
+                color
+            )
+            .unwrap();
+        }
+        if self.has_buildings {
+            writeln!(out, "    buildings [label=\"Buildings\", shape=box3d];").unwrap();
+        }
+        for &(from, to, count) in self.edges.iter() {
+            if to == Node::Buildings {
+                writeln!(out, "    {} -> {};", from.id(), to.id()).unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "    {} -> {} [label=\"{}\"];",
+                    from.id(),
+                    to.id(),
+                    count
+                )
+                .unwrap();
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph LR\n");
+        for &item in self.items.iter() {
+            writeln!(out, "    {}([{:?}])", Node::Item(item).id(), item).unwrap();
+        }
+        for &(recipe, _) in self.recipes.iter() {
+            writeln!(out, "    {}[{:?}]", Node::Recipe(recipe).id(), recipe).unwrap();
+        }
+        if self.has_buildings {
+            writeln!(out, "    buildings[[Buildings]]").unwrap();
+        }
+        for &(from, to, count) in self.edges.iter() {
+            if to == Node::Buildings {
+                writeln!(out, "    {} --> {}", from.id(), to.id()).unwrap();
+            } else {
+                writeln!(out, "    {} -->|{}| {}", from.id(), count, to.id()).unwrap();
+            }
+        }
+        if self.options.color_by_producer {
+            let mut used: EnumMap<Producer, bool> = EnumMap::from(|_| false);
+            for &(_, producer) in self.recipes.iter() {
+                used[producer] = true;
+            }
+            for (producer, _) in used.iter().filter(|(_, &used)| used) {
+                writeln!(
+                    out,
+                    "    classDef {:?} fill:{}",
+                    producer,
+                    producer_color(producer)
+                )
+                .unwrap();
+            }
+            for &(recipe, producer) in self.recipes.iter() {
+                writeln!(
+                    out,
+                    "    class {} {:?}",
+                    Node::Recipe(recipe).id(),
+                    producer
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn producer_color(producer: Producer) -> &'static str {
+    match producer {
+        Producer::AssemblingMachine => "#8dd3c7",
+        Producer::Smelter => "#ffffb3",
+        Producer::OilRefinery => "#bebada",
+        Producer::ChemicalPlant => "#fb8072",
+        Producer::Fractionator => "#80b1d3",
+        Producer::MatrixLab => "#fdb462",
+        Producer::MiniatureParticleCollider => "#b3de69",
+        Producer::MiningMachine => "#fccde5",
+        Producer::OilExtractor => "#d9d9d9",
+        Producer::RayReceiver => "#bc80bd",
+        Producer::WaterPump => "#ccebc5",
+        Producer::OrbitCollector => "#ffed6f",
+        Producer::EnergyExchanger => "#e5c494",
+        Producer::Mecha => "#b3b3b3",
+    }
+}
@@ -1,8 +1,10 @@
 use enum_map::Enum;
 use schemars::JsonSchema;
 use serde::Serialize;
+use strum_macros::{Display, EnumIter};
 
-#[derive(Debug, Clone, Copy, Enum, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum, EnumIter, Display, Serialize, JsonSchema)]
+#[strum(serialize_all = "title_case")]
 pub enum Recipe {
     IronSmelting,
     CopperSmelting,
@@ -21,6 +23,7 @@ pub enum Recipe {
     GlassSmelting,
     DiamondFromGraphite,
     DiamondFromKimberlite,
+    #[strum(to_string = "X-Ray Cracking")]
     XRayCracking,
     OrganicCrystalFromWood,
     OrganicCrystalFromPlastic,
@@ -70,11 +73,13 @@ pub enum Recipe {
     LogisticsVessel,
     Motor,
     ElectromagneticTurbine,
+    #[strum(to_string = "Particle Container From EM Turbine")]
     ParticleContainerFromEMTurbine,
     ParticleContainerFromUnipolar,
     SuperMagneticRing,
     StrangeMatter,
     GravitonLens,
+    SolarSail,
 
     TeslaTower,
     WirelessPowerTower,
@@ -82,27 +87,40 @@ pub enum Recipe {
     WindTurbine,
     ThermalPowerStation,
     SolarPanel,
+    MiniFusionPowerStation,
+    #[strum(to_string = "Conveyor MK.I")]
     ConveyorMK1,
+    #[strum(to_string = "Conveyor MK.II")]
     ConveyorMK2,
+    #[strum(to_string = "Conveyor MK.III")]
     ConveyorMK3,
     Splitter,
+    #[strum(to_string = "Storage MK.I")]
     StorageMK1,
+    #[strum(to_string = "Storage MK.II")]
     StorageMK2,
+    #[strum(to_string = "Sorter MK.I")]
     SorterMK1,
+    #[strum(to_string = "Sorter MK.II")]
     SorterMK2,
+    #[strum(to_string = "Sorter MK.III")]
     SorterMK3,
     MiningMachine,
     WaterPump,
     OilExtractor,
     OilRefinery,
+    #[strum(to_string = "Assembling Machine MK.I")]
     AssemblingMachineMK1,
+    #[strum(to_string = "Assembling Machine MK.II")]
     AssemblingMachineMK2,
+    #[strum(to_string = "Assembling Machine MK.III")]
     AssemblingMachineMK3,
     Smelter,
     Fractionator,
     ChemicalPlant,
     MatrixLab,
     RayReceiver,
+    #[strum(to_string = "EM Rail Ejector")]
     EMRailEjector,
     MiniatureParticleCollider,
     ArtificialStar,
@@ -133,4 +151,8 @@ pub enum Recipe {
     SulfuricAcidPumping,
     GasGiantCollection,
     IceGiantCollection,
+    TreeHarvesting,
+    PlantHarvesting,
+    ChargeAccumulator,
+    DischargeAccumulator,
 }
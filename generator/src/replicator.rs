@@ -0,0 +1,179 @@
+use crate::{calculator::net_output, generator::AllDSPInfo, items::Item, recipes::Recipe};
+
+use anyhow::ensure;
+use enum_map::EnumMap;
+
+/// Works out what Icarus has to make in the replicator to get some items.
+pub struct Replicator<'a> {
+    info: &'a AllDSPInfo,
+    /// What's already in the inventory or in storage, which gets used before crafting anything.
+    pub storage: EnumMap<Item, u32>,
+    /// How fast the replicator works. This is 1.0 with no mecha upgrades.
+    pub speed: f64,
+}
+
+/// Everything it takes to handcraft something.
+#[derive(Debug)]
+pub struct HandcraftPlan {
+    pub item: Item,
+    pub count: u32,
+    /// How many times to run each recipe in the replicator, in the order of `Recipe`.
+    pub crafts: Vec<Handcraft>,
+    /// Items to take out of storage.
+    pub from_storage: EnumMap<Item, u32>,
+    /// Items that have to be mined, gathered or made in a factory, because the replicator can't make them
+    /// and there aren't enough in storage.
+    pub missing: EnumMap<Item, u32>,
+    /// Extra items left over from crafts that make more than needed.
+    pub leftovers: EnumMap<Item, u32>,
+    /// How long all the crafting takes, in seconds.
+    pub total_time: f64,
+}
+
+/// One recipe run some number of times in the replicator.
+#[derive(Debug, Clone, Copy)]
+pub struct Handcraft {
+    pub recipe: Recipe,
+    pub crafts: u32,
+    /// How long these crafts take, in seconds.
+    pub time: f64,
+}
+
+impl<'a> Replicator<'a> {
+    pub fn new(info: &'a AllDSPInfo) -> Self {
+        Self {
+            info,
+            storage: EnumMap::from(|_| 0),
+            speed: 1.0,
+        }
+    }
+
+    /// Get the recipe the replicator would use to make the item, if it can make it at all.
+    pub fn recipe_for(&self, item: Item) -> Option<Recipe> {
+        self.info.production_methods.0[item]
+            .iter()
+            .copied()
+            .find(|&recipe| {
+                let entry = &self.info.recipes.0[recipe];
+                entry.handcraftable && net_output(entry, item) >= 1.0
+            })
+    }
+
+    /// Figure out how to get `count` of the item, using up storage first.
+    ///
+    /// The replicator only deals in whole items, so this fails if a recipe it
+    /// needs has a fractional count anywhere.
+    pub fn plan(&self, item: Item, count: u32) -> anyhow::Result<HandcraftPlan> {
+        let mut storage = self.storage;
+        let mut crafts = EnumMap::from(|_| 0);
+        let mut from_storage = EnumMap::from(|_| 0);
+        let mut missing = EnumMap::from(|_| 0);
+        let mut leftovers = EnumMap::from(|_| 0);
+        self.demand(
+            item,
+            count,
+            &mut storage,
+            &mut crafts,
+            &mut from_storage,
+            &mut missing,
+            &mut leftovers,
+            &mut Vec::new(),
+        )?;
+
+        let crafts: Vec<_> = crafts
+            .iter()
+            .filter(|(_, &crafts)| crafts > 0)
+            .map(|(recipe, &crafts)| Handcraft {
+                recipe,
+                crafts,
+                time: crafts as f64 * self.info.recipes.0[recipe].time as f64 / self.speed,
+            })
+            .collect();
+        let total_time = crafts.iter().map(|craft| craft.time).sum();
+
+        Ok(HandcraftPlan {
+            item,
+            count,
+            crafts,
+            from_storage,
+            missing,
+            leftovers,
+            total_time,
+        })
+    }
+
+    /// Get `count` of the item from leftovers, storage or the replicator, in that order.
+    ///
+    /// `making` is all the items we're in the middle of figuring out, so loops
+    /// count as missing instead of going forever.
+    #[allow(clippy::too_many_arguments)]
+    fn demand(
+        &self,
+        item: Item,
+        mut count: u32,
+        storage: &mut EnumMap<Item, u32>,
+        crafts: &mut EnumMap<Recipe, u32>,
+        from_storage: &mut EnumMap<Item, u32>,
+        missing: &mut EnumMap<Item, u32>,
+        leftovers: &mut EnumMap<Item, u32>,
+        making: &mut Vec<Item>,
+    ) -> anyhow::Result<()> {
+        let leftover = count.min(leftovers[item]);
+        leftovers[item] -= leftover;
+        count -= leftover;
+
+        let stored = count.min(storage[item]);
+        storage[item] -= stored;
+        from_storage[item] += stored;
+        count -= stored;
+        if count == 0 {
+            return Ok(());
+        }
+
+        let recipe = match self.recipe_for(item) {
+            Some(recipe) if !making.contains(&item) => recipe,
+            _ => {
+                missing[item] += count;
+                return Ok(());
+            }
+        };
+        let entry = &self.info.recipes.0[recipe];
+        let whole = |count: f64| -> anyhow::Result<u32> {
+            ensure!(
+                count >= 0.0 && count.fract() == 0.0,
+                "{:?} has {} of something, but the replicator only does whole items",
+                recipe,
+                count
+            );
+            Ok(count as u32)
+        };
+        // Only whole crafts, so there might be some extra
+        let per_craft = whole(net_output(entry, item))?;
+        let times = count.div_ceil(per_craft);
+        crafts[recipe] += times;
+        leftovers[item] += times * per_craft - count;
+
+        for result in entry.results.iter() {
+            if result.item != item {
+                leftovers[result.item] += whole(result.count)? * times;
+            }
+        }
+        making.push(item);
+        for ingredient in entry.ingredients.iter() {
+            if ingredient.item != item {
+                self.demand(
+                    ingredient.item,
+                    whole(ingredient.count)? * times,
+                    storage,
+                    crafts,
+                    from_storage,
+                    missing,
+                    leftovers,
+                    making,
+                )?;
+            }
+        }
+        making.pop();
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Keeping `dsp.json` stable for whoever reads it.
+//!
+//! Every time the shape of `AllDSPInfo` changes, bump `SCHEMA_VERSION`, run the generator
+//! to write the new schema to `schema/`, and teach `migrate` how to upgrade the old shape.
+//! The old schema files stay around so older consumers can keep checking against them.
+
+use crate::generator::AllDSPInfo;
+
+use anyhow::{anyhow, bail};
+use schemars::{schema::RootSchema, schema_for};
+use serde_json::Value;
+
+use std::path::PathBuf;
+
+/// The version of the shape of `dsp.json`.
+///
+/// Version 0 is everything from before this field existed.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// What the schema for the given version calls itself.
+pub fn schema_id(version: u32) -> String {
+    format!("urn:center-brain-archive:dsp:v{}", version)
+}
+
+/// Where the schema file for the given version lives.
+pub fn schema_path(version: u32) -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/schema"))
+        .join(format!("dsp-v{}.schema.json", version))
+}
+
+/// Make the JSON Schema for the current version of `AllDSPInfo`.
+pub fn schema() -> RootSchema {
+    let mut schema = schema_for!(AllDSPInfo);
+    schema.schema.metadata().id = Some(schema_id(SCHEMA_VERSION));
+    schema
+}
+
+/// Upgrade a `dsp.json` from any older version to the current one.
+///
+/// Anything that was added since the old version gets filled in from freshly generated data,
+/// and anything already there is left alone.
+pub fn migrate(mut doc: Value) -> anyhow::Result<Value> {
+    let version = match doc.get("schema_version") {
+        None => 0,
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| anyhow!("schema_version should be a number, not {}", version))?
+            as u32,
+    };
+    if version > SCHEMA_VERSION {
+        bail!(
+            "This is version {} of dsp.json, but the newest I know about is {}",
+            version,
+            SCHEMA_VERSION
+        );
+    }
+
+    if version < 1 {
+        // 0 -> 1: the tech tree got closures and depths, and the layout, buildings and fuel
+        // got added. All of those can be generated again.
+        if !doc.is_object() {
+            bail!("dsp.json should be an object");
+        }
+        fill_missing(&mut doc, &serde_json::to_value(AllDSPInfo::generate())?);
+        doc["schema_version"] = 1.into();
+    }
+    if version < 2 {
+        // 1 -> 2: display names got added
+        fill_missing(&mut doc, &serde_json::to_value(AllDSPInfo::generate())?);
+        doc["schema_version"] = 2.into();
+    }
+
+    Ok(doc)
+}
+
+/// Copy everything in `new` that isn't in `old` into `old`.
+fn fill_missing(old: &mut Value, new: &Value) {
+    if let (Value::Object(old), Value::Object(new)) = (old, new) {
+        for (key, value) in new {
+            match old.get_mut(key) {
+                Some(existing) => fill_missing(existing, value),
+                None => {
+                    old.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
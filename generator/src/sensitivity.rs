@@ -0,0 +1,277 @@
+//! What-ifs for production plans: how much would faster machines, proliferator or another
+//! recipe actually save?
+//!
+//! Each change gets planned from scratch with the same targets, and compared to the plan
+//! without it, so you can see which research or upgrade is worth it for what you're making.
+
+use crate::{
+    calculator::{net_output, Calculator},
+    items::{Item, ItemStack},
+    producers::Producer,
+    recipes::Recipe,
+};
+
+use anyhow::{anyhow, ensure};
+use enum_map::EnumMap;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use std::fmt::{self, Display, Formatter};
+
+/// Anything smaller than this is just floating-point noise.
+const EPSILON: f64 = 1e-9;
+
+/// Something to try changing about a plan.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum Change {
+    /// Multiply how long the recipe takes.
+    RecipeTime(Recipe, f64),
+    /// Proliferate every ingredient at this level, from 0 to 3.
+    ///
+    /// This only adds the extra products; the spray it takes isn't planned. That makes it
+    /// look better than it is, so `changes_to_try` leaves it out.
+    Proliferator(u32),
+    /// Build this building for its producer's machines, like `AssemblingMachineMK3`.
+    MachineTier(Item),
+    /// Make the item with this recipe instead.
+    Substitute(Item, Recipe),
+}
+
+/// What a plan comes out to.
+#[derive(Debug, Clone, Serialize)]
+pub struct Outcome {
+    /// How many machines of each kind. These are fractional.
+    pub machines: EnumMap<Producer, f64>,
+    /// Raw items per minute, from `ProductionPlan::raw_inputs`.
+    pub raw: EnumMap<Item, f64>,
+}
+
+/// One change and what it does.
+#[derive(Debug, Clone, Serialize)]
+pub struct Scenario {
+    pub change: Change,
+    pub outcome: Outcome,
+}
+
+/// A bunch of changes compared to the plan without them.
+#[derive(Debug, Clone, Serialize)]
+pub struct Sensitivity {
+    pub targets: Vec<ItemStack>,
+    pub base: Outcome,
+    pub scenarios: Vec<Scenario>,
+}
+
+/// How many extra products each proliferator level gives, as a fraction.
+pub fn proliferator_bonus(level: u32) -> Option<f64> {
+    match level {
+        0 => Some(0.0),
+        1 => Some(0.125),
+        2 => Some(0.2),
+        3 => Some(0.25),
+        _ => None,
+    }
+}
+
+impl Change {
+    /// Set up a copy of the calculator with this change made.
+    ///
+    /// Fails if the change doesn't make sense, like a substitute recipe that doesn't make the item.
+    pub fn apply<'a>(self, calculator: &Calculator<'a>) -> anyhow::Result<Calculator<'a>> {
+        let mut calculator = calculator.clone();
+        match self {
+            Change::RecipeTime(recipe, scale) => {
+                ensure!(
+                    scale.is_finite() && scale > 0.0,
+                    "Recipes can't take {}x as long",
+                    scale
+                );
+                calculator.time_scale[recipe] *= scale;
+            }
+            Change::Proliferator(level) => {
+                calculator.extra_products = proliferator_bonus(level)
+                    .ok_or_else(|| anyhow!("Proliferator only goes up to 3, not {}", level))?;
+            }
+            Change::MachineTier(item) => {
+                let building = item
+                    .building()
+                    .filter(|building| building.producer.is_some())
+                    .ok_or_else(|| anyhow!("{} isn't a building that makes things", item))?;
+                calculator.speeds[building.producer.unwrap()] = building.speed;
+            }
+            Change::Substitute(item, recipe) => {
+                ensure!(
+                    net_output(&calculator.info().recipes.0[recipe], item) > EPSILON,
+                    "{} doesn't make any {}",
+                    recipe,
+                    item
+                );
+                calculator.recipe_choices[item] = Some(recipe);
+            }
+        }
+        Ok(calculator)
+    }
+}
+
+impl Outcome {
+    /// Plan the targets and add it all up.
+    pub fn of(calculator: &Calculator, targets: &[ItemStack]) -> Self {
+        let plan = calculator.plan(targets);
+        let mut machines = EnumMap::from(|_| 0.0);
+        for line in plan.lines.iter() {
+            machines[line.made_in] += line.machines;
+        }
+        Self {
+            machines,
+            raw: plan.raw_inputs(calculator.info()),
+        }
+    }
+
+    pub fn total_machines(&self) -> f64 {
+        self.machines.values().sum()
+    }
+
+    pub fn total_raw(&self) -> f64 {
+        self.raw.values().sum()
+    }
+}
+
+/// Try each change on its own and see how the plan for the targets comes out.
+pub fn analyze(
+    calculator: &Calculator,
+    targets: &[ItemStack],
+    changes: &[Change],
+) -> anyhow::Result<Sensitivity> {
+    let scenarios = changes
+        .iter()
+        .map(|&change| {
+            Ok(Scenario {
+                change,
+                outcome: Outcome::of(&change.apply(calculator)?, targets),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Sensitivity {
+        targets: targets.to_vec(),
+        base: Outcome::of(calculator, targets),
+        scenarios,
+    })
+}
+
+/// Everything worth trying for these targets: every faster building for the machines
+/// in the plan, every other recipe for the items in it, and each of its non-mining
+/// recipes taking half as long.
+///
+/// Proliferator isn't in here, see `Change::Proliferator`.
+pub fn changes_to_try(calculator: &Calculator, targets: &[ItemStack]) -> Vec<Change> {
+    let info = calculator.info();
+    let plan = calculator.plan(targets);
+    let mut changes = Vec::new();
+
+    for producer in Producer::iter() {
+        if plan.lines.iter().all(|line| line.made_in != producer) {
+            continue;
+        }
+        for item in producer.buildings() {
+            if item.building().unwrap().speed > calculator.speeds[producer] + EPSILON {
+                changes.push(Change::MachineTier(item));
+            }
+        }
+    }
+
+    for item in Item::iter() {
+        let current = match calculator.recipe_for(item) {
+            Some(recipe) if plan.line(recipe).is_some() => recipe,
+            _ => continue,
+        };
+        for &recipe in info.production_methods.0[item].iter() {
+            if recipe != current && net_output(&info.recipes.0[recipe], item) > EPSILON {
+                changes.push(Change::Substitute(item, recipe));
+            }
+        }
+    }
+
+    // Mining goes by the mining model, not recipe times
+    changes.extend(
+        plan.lines
+            .iter()
+            .filter(|line| !info.recipes.0[line.recipe].ingredients.is_empty())
+            .map(|line| Change::RecipeTime(line.recipe, 0.5)),
+    );
+    changes
+}
+
+impl Sensitivity {
+    /// The scenarios, the ones that save the most machines first.
+    pub fn ranked(&self) -> Vec<&Scenario> {
+        let mut ranked: Vec<_> = self.scenarios.iter().collect();
+        ranked.sort_by(|a, b| {
+            a.outcome
+                .total_machines()
+                .total_cmp(&b.outcome.total_machines())
+        });
+        ranked
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::RecipeTime(recipe, scale) => write!(f, "{} takes {}x as long", recipe, scale),
+            Change::Proliferator(level) => write!(f, "Proliferator level {}", level),
+            Change::MachineTier(item) => write!(f, "Build {}", item),
+            Change::Substitute(item, recipe) => write!(f, "{} from {}", item, recipe),
+        }
+    }
+}
+
+impl Display for Sensitivity {
+    /// A table of the scenarios, best first, with how the machines and raw items change.
+    ///
+    /// Proliferator rows get a `*`, since they leave out what it takes to make the spray.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<48} {:>9} {:>9} {:>9} {:>9}  Raw changes",
+            "Change", "Machines", "Diff", "Raw/min", "Diff"
+        )?;
+        let row = |f: &mut Formatter<'_>, name: &str, outcome: &Outcome| {
+            let raw_changes: Vec<_> = Item::iter()
+                .filter_map(|item| {
+                    let diff = outcome.raw[item] - self.base.raw[item];
+                    if diff.abs() > 1e-6 {
+                        Some(format!("{:+.2} {}", diff, item))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            writeln!(
+                f,
+                "{:<48} {:>9.2} {:>+9.2} {:>9.2} {:>+9.2}  {}",
+                name,
+                outcome.total_machines(),
+                outcome.total_machines() - self.base.total_machines(),
+                outcome.total_raw(),
+                outcome.total_raw() - self.base.total_raw(),
+                raw_changes.join(", ")
+            )
+        };
+        row(f, "No change", &self.base)?;
+        let mut proliferated = false;
+        for scenario in self.ranked() {
+            let mut name = scenario.change.to_string();
+            if let Change::Proliferator(_) = scenario.change {
+                name.push_str(" *");
+                proliferated = true;
+            }
+            row(f, &name, &scenario.outcome)?;
+        }
+        if proliferated {
+            writeln!(
+                f,
+                "* Doesn't count the proliferator itself, or the machines and raw items to make it"
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+//! A little JSON API over HTTP, so other tools can ask questions while the game's running.
+//!
+//! Everything's a GET, and names can be anything `names::resolve` understands:
+//!
+//! - `/dsp.json`: everything.
+//! - `/items/{item}/produce`, `/items/{item}/consume`: the recipes that make or use the item.
+//! - `/recipes/{recipe}`
+//! - `/techs/{tech}`: the tech, with everything it needs and everything that needs it.
+//! - `/bom/{item}?count=N`: every craft and raw material it takes to make N of the item.
+//! - `/rate/{item}?per_minute=N`: the machines it takes to make N of the item a minute.
+//! - `/search?q=...`: recipes matching a `query::Query`.
+
+use crate::{
+    calculator::Calculator,
+    generator::{AllDSPInfo, RecipeEntry},
+    items::{Item, ItemStack},
+    names::{resolve, Named},
+    query::Query,
+    recipes::Recipe,
+};
+
+use enum_map::EnumMap;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tiny_http::{Header, Response, Server};
+
+/// A bill of materials: everything that goes into a batch of something.
+#[derive(Debug, Serialize)]
+pub struct BillOfMaterials {
+    pub item: Item,
+    pub count: f64,
+    /// How many times each recipe has to run.
+    pub crafts: Vec<(Recipe, f64)>,
+    /// Raw materials, and anything else that has to come from somewhere else.
+    pub raw: Vec<ItemStack>,
+    /// Leftover byproducts.
+    pub surplus: Vec<ItemStack>,
+}
+
+/// Work out the status code and JSON body for a request to `url`.
+///
+/// This doesn't touch the network, so it's easy to poke at without a server.
+pub fn handle(info: &AllDSPInfo, url: &str) -> (u16, Value) {
+    match route(info, url) {
+        Ok(body) => (200, body),
+        Err((status, message)) => (status, json!({ "error": message })),
+    }
+}
+
+type RouteResult = Result<Value, (u16, String)>;
+
+fn route(info: &AllDSPInfo, url: &str) -> RouteResult {
+    let (path, query) = match url.find('?') {
+        Some(idx) => (&url[..idx], &url[idx + 1..]),
+        None => (url, ""),
+    };
+    let segments: Vec<_> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(decode)
+        .collect();
+    let segments: Vec<_> = segments.iter().map(String::as_str).collect();
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| decode(value))
+    };
+    let number = |name: &str, default: f64| match param(name) {
+        Some(value) => match value.parse::<f64>() {
+            Ok(number) if number.is_finite() && number >= 0.0 => Ok(number),
+            _ => Err((
+                400,
+                format!("`{}` should be a number 0 or over, not `{}`", name, value),
+            )),
+        },
+        None => Ok(default),
+    };
+
+    match segments.as_slice() {
+        ["dsp.json"] => to_json(info),
+        ["items", item, "produce"] => recipes(info, &info.production_methods.0[name(item)?]),
+        ["items", item, "consume"] => recipes(info, &info.consumption_methods.0[name(item)?]),
+        ["recipes", recipe] => to_json(&info.recipes.0[name(recipe)?]),
+        ["techs", tech] => to_json(&info.tech_tree.0[name(tech)?]),
+        ["bom", item] => to_json(&bill_of_materials(info, name(item)?, number("count", 1.0)?)),
+        ["rate", item] => {
+            let target = ItemStack {
+                item: name(item)?,
+                count: number("per_minute", 60.0)?,
+            };
+            to_json(&Calculator::new(info).plan(&[target]))
+        }
+        ["search"] => {
+            let query: Query = param("q")
+                .unwrap_or_default()
+                .parse()
+                .map_err(|e| (400, format!("{}", e)))?;
+            recipes(info, &query.run(info))
+        }
+        _ => Err((404, format!("Nothing at {}", path))),
+    }
+}
+
+/// Work out everything it takes to make `count` of the item.
+///
+/// Anything that comes out of a recipe with no ingredients, like mining, counts as raw.
+pub fn bill_of_materials(info: &AllDSPInfo, item: Item, count: f64) -> BillOfMaterials {
+    // The calculator works in items per minute, but a batch is the same math
+    let plan = Calculator::new(info).plan(&[ItemStack { item, count }]);
+    let raw = plan.raw_inputs(info);
+    let crafts = plan
+        .lines
+        .iter()
+        .filter(|line| !info.recipes.0[line.recipe].ingredients.is_empty())
+        .map(|line| (line.recipe, line.crafts_per_minute))
+        .collect();
+
+    let stacks = |amounts: &EnumMap<Item, f64>| {
+        amounts
+            .iter()
+            .filter(|(_, &count)| count > 1e-9)
+            .map(|(item, &count)| ItemStack { item, count })
+            .collect()
+    };
+    BillOfMaterials {
+        item,
+        count,
+        crafts,
+        raw: stacks(&raw),
+        surplus: stacks(&plan.surplus),
+    }
+}
+
+fn name<T: Named>(text: &str) -> Result<T, (u16, String)> {
+    resolve(text).map_err(|e| (404, e.to_string()))
+}
+
+fn recipes(info: &AllDSPInfo, recipes: &[Recipe]) -> RouteResult {
+    let entries: Vec<&RecipeEntry> = recipes.iter().map(|&r| &info.recipes.0[r]).collect();
+    to_json(&entries)
+}
+
+fn to_json(it: &impl Serialize) -> RouteResult {
+    serde_json::to_value(it).map_err(|e| (500, e.to_string()))
+}
+
+/// Undo URL encoding, like `%20` and `+` for spaces.
+fn decode(text: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut iter = text.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = iter.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => bytes.push(byte),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Answer requests on the server forever.
+///
+/// Clients that hang up before getting their answer are logged and skipped.
+pub fn serve(info: &AllDSPInfo, server: &Server) {
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    for request in server.incoming_requests() {
+        let (status, body) = handle(info, request.url());
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(err) = request.respond(response) {
+            eprintln!("Couldn't answer a request: {}", err);
+        }
+    }
+}
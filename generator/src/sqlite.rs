@@ -0,0 +1,156 @@
+//! Writing all the info to a SQLite database, for poking at with SQL.
+
+use crate::{
+    generator::AllDSPInfo, items::Item, producers::Producer, recipes::Recipe,
+    technologies::Technology,
+};
+
+use enum_map::Enum;
+use rusqlite::{params, Connection};
+use strum::IntoEnumIterator;
+
+use std::path::Path;
+
+/// Every table, with foreign keys pointing back at the enum tables.
+///
+/// The `id` of each row is its variant's index in the enum, so it lines up with the indexed
+/// formats in `encode.rs`.
+const SCHEMA: &str = "
+CREATE TABLE producers (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+CREATE TABLE items (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    game_id INTEGER NOT NULL UNIQUE,
+    is_building INTEGER NOT NULL,
+    fuel_energy REAL
+);
+CREATE TABLE technologies (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    game_id INTEGER NOT NULL UNIQUE,
+    depth INTEGER NOT NULL,
+    steps_to_completion INTEGER
+);
+CREATE TABLE tech_prereqs (
+    tech_id INTEGER NOT NULL REFERENCES technologies(id),
+    prereq_id INTEGER NOT NULL REFERENCES technologies(id),
+    PRIMARY KEY (tech_id, prereq_id)
+);
+CREATE TABLE recipes (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    game_id INTEGER UNIQUE,
+    time REAL NOT NULL,
+    made_in INTEGER NOT NULL REFERENCES producers(id),
+    handcraftable INTEGER NOT NULL,
+    unlocked_by INTEGER NOT NULL REFERENCES technologies(id)
+);
+CREATE TABLE recipe_ingredients (
+    recipe_id INTEGER NOT NULL REFERENCES recipes(id),
+    item_id INTEGER NOT NULL REFERENCES items(id),
+    count REAL NOT NULL
+);
+CREATE TABLE recipe_results (
+    recipe_id INTEGER NOT NULL REFERENCES recipes(id),
+    item_id INTEGER NOT NULL REFERENCES items(id),
+    count REAL NOT NULL
+);
+CREATE INDEX recipe_ingredients_by_item ON recipe_ingredients(item_id);
+CREATE INDEX recipe_results_by_item ON recipe_results(item_id);
+";
+
+/// Write everything to a new database at the path, replacing whatever was there.
+pub fn export(info: &AllDSPInfo, path: &Path) -> anyhow::Result<()> {
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    let mut conn = Connection::open(path)?;
+    write_to(info, &mut conn)
+}
+
+/// Make all the tables in the connection and fill them in.
+pub fn write_to(info: &AllDSPInfo, conn: &mut Connection) -> anyhow::Result<()> {
+    conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+    let tx = conn.transaction()?;
+    tx.execute_batch(SCHEMA)?;
+
+    for producer in Producer::iter() {
+        tx.execute(
+            "INSERT INTO producers (id, name) VALUES (?1, ?2)",
+            params![id(producer), format!("{:?}", producer)],
+        )?;
+    }
+
+    for item in Item::iter() {
+        tx.execute(
+            "INSERT INTO items (id, name, game_id, is_building, fuel_energy) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                id(item),
+                format!("{:?}", item),
+                item.game_id(),
+                item.is_building(),
+                info.fuel_energy.0[item],
+            ],
+        )?;
+    }
+
+    for tech in Technology::iter() {
+        let entry = &info.tech_tree.0[tech];
+        tx.execute(
+            "INSERT INTO technologies (id, name, game_id, depth, steps_to_completion) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                id(tech),
+                format!("{:?}", tech),
+                tech.game_id(),
+                entry.depth,
+                entry.steps_to_completion,
+            ],
+        )?;
+    }
+    for tech in Technology::iter() {
+        for &pre in info.tech_tree.0[tech].prereqs.iter() {
+            tx.execute(
+                "INSERT INTO tech_prereqs (tech_id, prereq_id) VALUES (?1, ?2)",
+                params![id(tech), id(pre)],
+            )?;
+        }
+    }
+
+    for recipe in Recipe::iter() {
+        let entry = &info.recipes.0[recipe];
+        tx.execute(
+            "INSERT INTO recipes (id, name, game_id, time, made_in, handcraftable, unlocked_by) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                id(recipe),
+                format!("{:?}", recipe),
+                recipe.game_id(),
+                entry.time as f64,
+                id(entry.made_in),
+                entry.handcraftable,
+                id(entry.unlocked_by),
+            ],
+        )?;
+        for stack in entry.ingredients.iter() {
+            tx.execute(
+                "INSERT INTO recipe_ingredients (recipe_id, item_id, count) VALUES (?1, ?2, ?3)",
+                params![id(recipe), id(stack.item), stack.count],
+            )?;
+        }
+        for stack in entry.results.iter() {
+            tx.execute(
+                "INSERT INTO recipe_results (recipe_id, item_id, count) VALUES (?1, ?2, ?3)",
+                params![id(recipe), id(stack.item), stack.count],
+            )?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+fn id<K: Enum<()>>(key: K) -> i64 {
+    key.to_usize() as i64
+}
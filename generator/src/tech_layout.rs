@@ -0,0 +1,243 @@
+use crate::technologies::Technology;
+
+use enum_map::EnumMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use std::fmt::Write;
+
+/// How many times to sweep up and down the layers reordering things.
+const SWEEPS: usize = 12;
+
+// Sizes for the SVG, in pixels
+const LAYER_WIDTH: u32 = 260;
+const ROW_HEIGHT: u32 = 50;
+const NODE_WIDTH: u32 = 220;
+const NODE_HEIGHT: u32 = 30;
+
+/// Where a technology goes when drawing the tree.
+#[derive(Debug, Clone, Copy, Default, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TechPosition {
+    /// How many techs deep this is from `DysonSphereProgram`, which is layer 0.
+    pub layer: u32,
+    /// Where this goes in its layer, starting from 0.
+    pub column: u32,
+}
+
+/// The whole tech tree laid out in layers.
+pub struct TechLayout {
+    pub positions: EnumMap<Technology, TechPosition>,
+    /// All the technologies in each layer, in column order.
+    pub layers: Vec<Vec<Technology>>,
+}
+
+impl TechLayout {
+    /// Put each tech in a layer by depth, and then shuffle the layers around
+    /// to get rid of as many crossed lines as possible.
+    pub fn generate() -> Self {
+        let mut layers = Self::by_depth().layers;
+        let mut best = layers.clone();
+        let mut best_crossings = crossings(&layers);
+        for sweep in 0..SWEEPS {
+            if sweep % 2 == 0 {
+                for idx in 1..layers.len() {
+                    reorder(&mut layers, idx, true);
+                }
+            } else {
+                for idx in (0..layers.len().saturating_sub(1)).rev() {
+                    reorder(&mut layers, idx, false);
+                }
+            }
+            let now = crossings(&layers);
+            if now < best_crossings {
+                best = layers.clone();
+                best_crossings = now;
+            }
+        }
+
+        Self::from_layers(best)
+    }
+
+    /// Put each tech in a layer by depth, in `Technology` order, without trying
+    /// to untangle anything.
+    pub fn by_depth() -> Self {
+        let depths = depths();
+        let layer_count = depths.values().max().map_or(0, |&max| max + 1);
+        let mut layers = vec![Vec::new(); layer_count as usize];
+        for tech in Technology::iter() {
+            layers[depths[tech] as usize].push(tech);
+        }
+        Self::from_layers(layers)
+    }
+
+    fn from_layers(layers: Vec<Vec<Technology>>) -> Self {
+        let mut positions = EnumMap::from(|_| TechPosition::default());
+        for (layer, techs) in layers.iter().enumerate() {
+            for (column, &tech) in techs.iter().enumerate() {
+                positions[tech] = TechPosition {
+                    layer: layer as u32,
+                    column: column as u32,
+                };
+            }
+        }
+        Self { positions, layers }
+    }
+
+    /// How many times edges cross each other in this layout.
+    pub fn crossings(&self) -> usize {
+        crossings(&self.layers)
+    }
+
+    /// Render the tree as an SVG, with layers going left to right.
+    pub fn to_svg(&self) -> String {
+        let rows = self.layers.iter().map(Vec::len).max().unwrap_or(0) as u32;
+        let width = self.layers.len() as u32 * LAYER_WIDTH;
+        let height = rows * ROW_HEIGHT;
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"sans-serif\" font-size=\"12\">\n",
+            width, height
+        );
+
+        let corner = |tech: Technology| {
+            let pos = self.positions[tech];
+            (pos.layer * LAYER_WIDTH, pos.column * ROW_HEIGHT)
+        };
+        for tech in Technology::iter() {
+            let (x, y) = corner(tech);
+            for &pre in tech.prerequisites() {
+                let (px, py) = corner(pre);
+                writeln!(
+                    out,
+                    "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#888\"/>",
+                    px + NODE_WIDTH,
+                    py + NODE_HEIGHT / 2,
+                    x,
+                    y + NODE_HEIGHT / 2
+                )
+                .unwrap();
+            }
+        }
+        for tech in Technology::iter() {
+            let (x, y) = corner(tech);
+            writeln!(
+                out,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"4\" fill=\"#ddeeff\" stroke=\"#336\"/>",
+                x, y, NODE_WIDTH, NODE_HEIGHT
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{:?}</text>",
+                x + NODE_WIDTH / 2,
+                y + NODE_HEIGHT / 2,
+                tech
+            )
+            .unwrap();
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Render the tree in Graphviz's DOT language, keeping each layer in the same rank.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph tech_tree {\n    rankdir=LR;\n    node [shape=box];\n");
+        for techs in self.layers.iter() {
+            let names: Vec<_> = techs.iter().map(|tech| format!("{:?}", tech)).collect();
+            writeln!(out, "    {{ rank=same; {}; }}", names.join("; ")).unwrap();
+        }
+        for tech in Technology::iter() {
+            for &pre in tech.prerequisites() {
+                writeln!(out, "    {:?} -> {:?};", pre, tech).unwrap();
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Work out `Technology::depth` for every tech at once, so shared prereqs only get walked once.
+fn depths() -> EnumMap<Technology, u32> {
+    fn depth(tech: Technology, known: &mut EnumMap<Technology, Option<u32>>) -> u32 {
+        if let Some(depth) = known[tech] {
+            return depth;
+        }
+        let found = tech
+            .prerequisites()
+            .iter()
+            .map(|&pre| depth(pre, known) + 1)
+            .max()
+            .unwrap_or(0);
+        known[tech] = Some(found);
+        found
+    }
+
+    let mut known = EnumMap::from(|_| None);
+    EnumMap::from(|tech| depth(tech, &mut known))
+}
+
+/// Sort the layer at `idx` by the average column of its neighbors,
+/// looking at prerequisites if `down` and dependents otherwise.
+fn reorder(layers: &mut [Vec<Technology>], idx: usize, down: bool) {
+    let mut columns: EnumMap<Technology, f64> = EnumMap::from(|_| 0.0);
+    for techs in layers.iter() {
+        for (column, &tech) in techs.iter().enumerate() {
+            columns[tech] = column as f64;
+        }
+    }
+
+    let mut keyed: Vec<(f64, Technology)> = layers[idx]
+        .iter()
+        .map(|&tech| {
+            let neighbors: Vec<Technology> = if down {
+                tech.prerequisites().to_vec()
+            } else {
+                Technology::iter()
+                    .filter(|other| other.prerequisites().contains(&tech))
+                    .collect()
+            };
+            let key = if neighbors.is_empty() {
+                // Nothing to line up with, so stay put
+                columns[tech]
+            } else {
+                neighbors.iter().map(|&n| columns[n]).sum::<f64>() / neighbors.len() as f64
+            };
+            (key, tech)
+        })
+        .collect();
+    // This is a stable sort, so ties keep their old order
+    keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+    layers[idx] = keyed.into_iter().map(|(_, tech)| tech).collect();
+}
+
+/// Count every pair of edges between the same two layers that cross.
+fn crossings(layers: &[Vec<Technology>]) -> usize {
+    let mut place: EnumMap<Technology, (usize, usize)> = EnumMap::from(|_| (0, 0));
+    for (layer, techs) in layers.iter().enumerate() {
+        for (column, &tech) in techs.iter().enumerate() {
+            place[tech] = (layer, column);
+        }
+    }
+
+    // (from layer, to layer, from column, to column)
+    let edges: Vec<_> = Technology::iter()
+        .flat_map(|tech| {
+            tech.prerequisites().iter().map(move |&pre| {
+                let (from_layer, from_col) = place[pre];
+                let (to_layer, to_col) = place[tech];
+                (from_layer, to_layer, from_col, to_col)
+            })
+        })
+        .collect();
+
+    let mut count = 0;
+    for (idx, a) in edges.iter().enumerate() {
+        for b in edges[idx + 1..].iter() {
+            if (a.0, a.1) == (b.0, b.1) && (a.2 < b.2) != (a.3 < b.3) && a.2 != b.2 && a.3 != b.3 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
@@ -1,9 +1,11 @@
-use enum_map::Enum;
+use enum_map::{Enum, EnumMap};
 use schemars::JsonSchema;
 use serde::Serialize;
-use strum_macros::EnumIter;
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
 
-#[derive(Enum, Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, JsonSchema)]
+#[derive(Enum, Clone, Copy, Debug, PartialEq, Eq, EnumIter, Display, Serialize, JsonSchema)]
+#[strum(serialize_all = "title_case")]
 pub enum Technology {
     DysonSphereProgram,
     Electromagnetism,
@@ -19,6 +21,7 @@ pub enum Technology {
     SmeltingPurification,
     ThermalPower,
     PlasmaExtractRefining,
+    #[strum(to_string = "Accelerant MK.I")]
     AccelerantMK1,
     EnvironmentModification,
     CrystalSmelting,
@@ -35,6 +38,7 @@ pub enum Technology {
     Processor,
     AppliedSuperconductor,
     PolymerChemicalEngineering,
+    #[strum(to_string = "X-Ray Cracking")]
     XRayCracking,
     HydrogenFuelRod,
     SuperMagneticFieldGenerator,
@@ -43,6 +47,7 @@ pub enum Technology {
     HighSpeedAssemblingProcesses,
     HighStrengthCrystal,
     Thruster,
+    #[strum(to_string = "Accelerant MK.II")]
     AccelerantMK2,
     MagneticParticleTrap,
     HighStrengthTitaniumAlloy,
@@ -58,6 +63,7 @@ pub enum Technology {
     HighStrengthGlass,
     CasimirCrystal,
     MiniatureParticleCollider,
+    #[strum(to_string = "Accelerant MK.III")]
     AccelerantMK3,
     SatellitePowerDistributionSystem,
     GasGiantsExplotiation,
@@ -154,7 +160,7 @@ impl Technology {
             Technology::ParticleControlTechnology => &[Technology::HighStrengthMaterial],
             Technology::HighStrengthGlass => &[Technology::HighStrengthMaterial],
             Technology::CasimirCrystal => &[Technology::StructureMatrix],
-            Technology::MiniatureParticleCollider => &[Technology::MiniatureParticleCollider],
+            Technology::MiniatureParticleCollider => &[Technology::MagneticParticleTrap],
             Technology::AccelerantMK3 => &[Technology::AccelerantMK2],
             Technology::SatellitePowerDistributionSystem => {
                 &[Technology::SuperMagneticFieldGenerator]
@@ -193,8 +199,33 @@ impl Technology {
             Technology::MissionCompleted => &[Technology::UniverseMatrix],
         }
     }
+
+    /// How many techs deep this is, counting `DysonSphereProgram` as 0.
+    ///
+    /// This goes by the longest way there, so a tech is always deeper than all its prereqs.
+    pub fn depth(self) -> u32 {
+        self.prerequisites()
+            .iter()
+            .map(|pre| pre.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get every technology that has to be researched at some point before this one.
+    pub fn all_prerequisites(self) -> Vec<Technology> {
+        let mut needed = EnumMap::from(|_| false);
+        let mut todo = self.prerequisites().to_vec();
+        while let Some(tech) = todo.pop() {
+            if !needed[tech] {
+                needed[tech] = true;
+                todo.extend_from_slice(tech.prerequisites());
+            }
+        }
+        Technology::iter().filter(|&tech| needed[tech]).collect()
+    }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Technology {
     fn default() -> Self {
         Technology::DysonSphereProgram
@@ -0,0 +1,257 @@
+use crate::{
+    items::{Item, ItemStack},
+    logistics::{carrier_stats, Carrier, LogisticsModel},
+    mining::MiningModel,
+};
+
+use anyhow::ensure;
+use enum_map::{Enum, EnumMap};
+use schemars::JsonSchema;
+use serde::Serialize;
+use strum_macros::EnumIter;
+
+/// The matrices in the order you unlock them.
+pub const MATRICES: [Item; 6] = [
+    Item::ElectromagneticMatrix,
+    Item::EnergyMatrix,
+    Item::StructureMatrix,
+    Item::InformationMatrix,
+    Item::GravityMatrix,
+    Item::UniverseMatrix,
+];
+
+/// Technologies you research over and over for bonuses, instead of to unlock something.
+#[derive(Enum, Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, JsonSchema)]
+pub enum Upgrade {
+    MechaCore,
+    MechanicalFrame,
+    DriveEngine,
+    InventoryCapacity,
+    CommunicationControl,
+    MassConstruction,
+    EnergyCircuit,
+    LogisticsCarrierEngine,
+    LogisticsCarrierCapacity,
+    VeinUtilization,
+    ResearchSpeed,
+}
+
+/// What one level of an upgrade does.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, JsonSchema)]
+pub enum UpgradeEffect {
+    /// Added to the mining speed multiplier.
+    MiningSpeed(f64),
+    /// Items added to each drone's capacity.
+    DroneCapacity(f64),
+    /// Items added to each vessel's capacity.
+    VesselCapacity(f64),
+    /// Added to the drone and vessel speed multiplier.
+    CarrierSpeed(f64),
+    /// Hashes per second added to each matrix lab.
+    ResearchSpeed(f64),
+    /// Makes the mecha better somehow, which doesn't matter to the factory.
+    Mecha(&'static str),
+}
+
+impl Upgrade {
+    /// The most levels you can research, or `None` if it goes on forever.
+    pub fn max_level(self) -> Option<u32> {
+        match self {
+            Upgrade::MechaCore => Some(6),
+            Upgrade::MechanicalFrame => Some(5),
+            Upgrade::DriveEngine => Some(4),
+            Upgrade::InventoryCapacity => Some(4),
+            Upgrade::CommunicationControl => Some(5),
+            Upgrade::MassConstruction => Some(3),
+            Upgrade::EnergyCircuit => Some(5),
+            Upgrade::LogisticsCarrierEngine => Some(6),
+            Upgrade::LogisticsCarrierCapacity => Some(8),
+            Upgrade::VeinUtilization => None,
+            Upgrade::ResearchSpeed => None,
+        }
+    }
+
+    /// What each level of this upgrade does.
+    pub fn effects(self) -> &'static [UpgradeEffect] {
+        match self {
+            Upgrade::MechaCore => &[UpgradeEffect::Mecha("more core energy")],
+            Upgrade::MechanicalFrame => &[UpgradeEffect::Mecha("faster walking")],
+            Upgrade::DriveEngine => &[UpgradeEffect::Mecha(
+                "faster flying, and eventually warping",
+            )],
+            Upgrade::InventoryCapacity => &[UpgradeEffect::Mecha("another row of inventory")],
+            Upgrade::CommunicationControl => &[UpgradeEffect::Mecha("more construction drones")],
+            Upgrade::MassConstruction => &[UpgradeEffect::Mecha("bigger blueprints")],
+            Upgrade::EnergyCircuit => &[UpgradeEffect::Mecha("faster core recharging")],
+            Upgrade::LogisticsCarrierEngine => &[UpgradeEffect::CarrierSpeed(0.1)],
+            Upgrade::LogisticsCarrierCapacity => &[
+                UpgradeEffect::DroneCapacity(5.0),
+                UpgradeEffect::VesselCapacity(100.0),
+            ],
+            Upgrade::VeinUtilization => &[UpgradeEffect::MiningSpeed(0.1)],
+            Upgrade::ResearchSpeed => &[UpgradeEffect::ResearchSpeed(1.0)],
+        }
+    }
+
+    /// Each level's cost as how many of each matrix it takes and how many kinds of matrix,
+    /// starting from Electromagnetic.
+    ///
+    /// The upgrades that go on forever keep the last level's matrices after this,
+    /// and go up by the last step each level.
+    fn cost_table(self) -> &'static [(u32, usize)] {
+        match self {
+            Upgrade::MechaCore => &[(80, 1), (160, 2), (240, 3), (320, 4), (600, 5), (1200, 6)],
+            Upgrade::MechanicalFrame => &[(120, 1), (180, 2), (240, 3), (400, 4), (800, 5)],
+            Upgrade::DriveEngine => &[(200, 1), (300, 2), (400, 3), (800, 4)],
+            Upgrade::InventoryCapacity => &[(100, 1), (200, 2), (300, 3), (600, 4)],
+            Upgrade::CommunicationControl => &[(100, 1), (200, 2), (300, 3), (400, 4), (800, 5)],
+            Upgrade::MassConstruction => &[(200, 2), (400, 3), (800, 4)],
+            Upgrade::EnergyCircuit => &[(100, 1), (200, 2), (300, 3), (600, 4), (1200, 5)],
+            Upgrade::LogisticsCarrierEngine => {
+                &[(200, 2), (300, 3), (400, 4), (600, 4), (800, 5), (1200, 6)]
+            }
+            Upgrade::LogisticsCarrierCapacity => &[
+                (200, 2),
+                (300, 2),
+                (400, 3),
+                (500, 3),
+                (600, 4),
+                (800, 4),
+                (1000, 5),
+                (1200, 6),
+            ],
+            Upgrade::VeinUtilization => &[(300, 3), (600, 4), (900, 5), (1200, 6)],
+            Upgrade::ResearchSpeed => &[(300, 3), (600, 4), (900, 5), (1200, 6)],
+        }
+    }
+
+    /// The matrices it takes to research the given level, starting at 1,
+    /// or `None` if there's no such level.
+    pub fn cost(self, level: u32) -> Option<Vec<ItemStack>> {
+        if level == 0 || self.max_level().is_some_and(|max| level > max) {
+            return None;
+        }
+        let table = self.cost_table();
+        let (count, kinds) = match table.get(level as usize - 1) {
+            Some(&entry) => entry,
+            None => {
+                let (last, kinds) = table[table.len() - 1];
+                let step = last - table[table.len() - 2].0;
+                (last + step * (level - table.len() as u32), kinds)
+            }
+        };
+        Some(
+            MATRICES[..kinds]
+                .iter()
+                .map(|&item| ItemStack {
+                    item,
+                    count: count as f64,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// How many levels of each upgrade you've researched.
+///
+/// This never goes over an upgrade's `max_level`.
+#[derive(Debug, Clone)]
+pub struct UpgradeLevels(EnumMap<Upgrade, u32>);
+
+impl Default for UpgradeLevels {
+    fn default() -> Self {
+        Self(EnumMap::from(|_| 0))
+    }
+}
+
+impl UpgradeLevels {
+    pub fn get(&self, upgrade: Upgrade) -> u32 {
+        self.0[upgrade]
+    }
+
+    /// Set how many levels of the upgrade are researched.
+    ///
+    /// Fails if that's more than the upgrade has.
+    pub fn set(&mut self, upgrade: Upgrade, level: u32) -> anyhow::Result<()> {
+        if let Some(max) = upgrade.max_level() {
+            ensure!(
+                level <= max,
+                "{:?} only goes up to level {}, not {}",
+                upgrade,
+                max,
+                level
+            );
+        }
+        self.0[upgrade] = level;
+        Ok(())
+    }
+
+    /// Add up every effect `pick` cares about, times how many levels of it there are.
+    pub fn total(&self, pick: impl Fn(UpgradeEffect) -> Option<f64>) -> f64 {
+        self.0
+            .iter()
+            .flat_map(|(upgrade, &level)| {
+                upgrade
+                    .effects()
+                    .iter()
+                    .filter_map(|&effect| pick(effect))
+                    .map(move |amount| amount * level as f64)
+            })
+            .sum()
+    }
+
+    /// The mining speed multiplier from research.
+    pub fn mining_speed(&self) -> f64 {
+        1.0 + self.total(|effect| match effect {
+            UpgradeEffect::MiningSpeed(amount) => Some(amount),
+            _ => None,
+        })
+    }
+
+    /// Hashes per second each matrix lab researches at.
+    pub fn research_speed(&self) -> f64 {
+        1.0 + self.total(|effect| match effect {
+            UpgradeEffect::ResearchSpeed(amount) => Some(amount),
+            _ => None,
+        })
+    }
+
+    /// How fast drones fly and how much they hold, after research.
+    pub fn drone(&self) -> Carrier {
+        self.carrier(Item::LogisticsDrone, |effect| match effect {
+            UpgradeEffect::DroneCapacity(amount) => Some(amount),
+            _ => None,
+        })
+    }
+
+    /// How fast vessels fly and how much they hold, after research.
+    pub fn vessel(&self) -> Carrier {
+        self.carrier(Item::LogisticsVessel, |effect| match effect {
+            UpgradeEffect::VesselCapacity(amount) => Some(amount),
+            _ => None,
+        })
+    }
+
+    fn carrier(&self, item: Item, capacity: impl Fn(UpgradeEffect) -> Option<f64>) -> Carrier {
+        let base = carrier_stats(item).unwrap();
+        let speed = 1.0
+            + self.total(|effect| match effect {
+                UpgradeEffect::CarrierSpeed(amount) => Some(amount),
+                _ => None,
+            });
+        Carrier {
+            speed: base.speed * speed,
+            capacity: base.capacity + self.total(capacity),
+        }
+    }
+
+    /// Set the mining model's speed to match this research.
+    pub fn apply_to_mining(&self, model: &mut MiningModel) {
+        model.speed = self.mining_speed();
+    }
+
+    /// Set the logistics model's vessels to match this research.
+    pub fn apply_to_logistics(&self, model: &mut LogisticsModel) {
+        model.vessel = self.vessel();
+    }
+}
@@ -0,0 +1,46 @@
+use generator::{
+    audit::{audit, parse_machines},
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::Item,
+    recipes::Recipe,
+};
+
+#[test]
+fn finds_bottlenecks_and_spares() {
+    let info = AllDSPInfo::generate();
+    let calculator = Calculator::new(&info);
+
+    // One smelter can't keep two gear assemblers going
+    let machines = parse_machines("iron smelting: 1, gear: 2").unwrap();
+    let report = audit(&calculator, &machines);
+    let gears = report
+        .lines
+        .iter()
+        .find(|l| l.recipe == Recipe::Gear)
+        .unwrap();
+    assert!((gears.utilization - 0.5).abs() < 1e-6);
+    assert_eq!(gears.starved_on, Some(Item::IronIngot));
+    assert_eq!(report.bottlenecks.len(), 1);
+    assert_eq!(report.bottlenecks[0].item, Item::IronIngot);
+    assert_eq!(report.bottlenecks[0].recipe, Recipe::IronSmelting);
+    assert!((report.bottlenecks[0].extra_machines - 1.0).abs() < 1e-6);
+
+    // And three is two too many for one
+    let machines = parse_machines("IronSmelting: 3\nGear: 1").unwrap();
+    let report = audit(&calculator, &machines);
+    let smelting = report.lines[0];
+    assert_eq!(smelting.recipe, Recipe::IronSmelting);
+    assert_eq!(smelting.spare_machines(), 2);
+    assert!((smelting.utilization - 1.0 / 3.0).abs() < 1e-6);
+    assert!(report.bottlenecks.is_empty());
+    assert!(report.to_string().contains("2 spare"));
+}
+
+#[test]
+fn bad_machine_lists() {
+    assert!(parse_machines("gear 2").is_err());
+    assert!(parse_machines("gear: lots").is_err());
+    assert!(parse_machines("not a recipe: 2").is_err());
+    assert_eq!(parse_machines("").unwrap()[Recipe::Gear], 0);
+}
@@ -0,0 +1,74 @@
+use generator::{
+    bootstrap::BootstrapPlanner, generator::AllDSPInfo, items::Item, recipes::Recipe,
+    technologies::Technology,
+};
+
+use enum_map::EnumMap;
+
+#[test]
+fn prereqs_come_first() {
+    let info = AllDSPInfo::generate();
+    let planner = BootstrapPlanner::new(&info);
+    let bootstrap = planner
+        .plan(Technology::InterstellarPowerTransmission)
+        .unwrap();
+    let order: Vec<_> = bootstrap.stages.iter().map(|stage| stage.tech).collect();
+    assert_eq!(
+        order.last(),
+        Some(&Technology::InterstellarPowerTransmission)
+    );
+    assert!(!order.contains(&Technology::DysonSphereProgram));
+
+    for (idx, stage) in bootstrap.stages.iter().enumerate() {
+        for prereq in stage.tech.prerequisites() {
+            if *prereq != Technology::DysonSphereProgram {
+                assert!(
+                    order[..idx].contains(prereq),
+                    "{:?} comes before its prereq {:?}",
+                    stage.tech,
+                    prereq
+                );
+            }
+        }
+        // Every matrix it needs is already unlocked
+        for &matrix in stage.estimated_matrices.iter() {
+            let unlocker = info.recipes.0[info.production_methods.0[matrix][0]].unlocked_by;
+            assert!(order[..idx].contains(&unlocker));
+        }
+    }
+
+    let last = bootstrap.stages.last().unwrap();
+    assert!(last.unlocks.contains(&Recipe::EnergyExchanger));
+    assert!(last
+        .estimated_matrices
+        .contains(&Item::ElectromagneticMatrix));
+}
+
+#[test]
+fn skips_whats_researched() {
+    let info = AllDSPInfo::generate();
+    let planner = BootstrapPlanner::new(&info);
+    let fresh = planner.plan(Technology::EnergyMatrix).unwrap();
+
+    let mut researched = EnumMap::from(|_| false);
+    researched[Technology::DysonSphereProgram] = true;
+    researched[Technology::Electromagnetism] = true;
+    researched[Technology::ElectromagneticMatrix] = true;
+    let later = planner
+        .plan_from(Technology::EnergyMatrix, &researched, &EnumMap::from(|_| 0))
+        .unwrap();
+
+    assert_eq!(later.stages.len(), fresh.stages.len() - 2);
+    assert!(later.stages.iter().all(|stage| !researched[stage.tech]));
+    assert_eq!(later.stages.last().unwrap().tech, Technology::EnergyMatrix);
+}
+
+#[test]
+fn unreachable_techs() {
+    // If electromagnetic matrices came after the techs that need them, nothing could get started
+    let mut info = AllDSPInfo::generate();
+    info.recipes.0[Recipe::ElectromagneticMatrix].unlocked_by = Technology::EnergyMatrix;
+    let planner = BootstrapPlanner::new(&info);
+    let err = planner.plan(Technology::EnergyMatrix).unwrap_err();
+    assert!(err.to_string().contains("PlasmaExtractRefining"), "{}", err);
+}
@@ -0,0 +1,59 @@
+use generator::{
+    build_list::BuildOrder,
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    producers::Producer,
+    recipes::Recipe,
+};
+
+#[test]
+fn gear_build_order() {
+    let info = AllDSPInfo::generate();
+    let mut calculator = Calculator::new(&info);
+    calculator.raw_items[Item::IronOre] = true;
+    let plan = calculator.plan(&[ItemStack {
+        item: Item::Gear,
+        count: 90.0,
+    }]);
+    let order = BuildOrder::new(&info, &plan);
+
+    let producers: Vec<_> = order.groups.iter().map(|group| group.producer).collect();
+    assert_eq!(
+        producers,
+        vec![Producer::AssemblingMachine, Producer::Smelter]
+    );
+    let gears = &order.groups[0].lines[0];
+    assert_eq!(gears.recipe, Recipe::Gear);
+    // 1.5 machines rounds up
+    assert_eq!(gears.machines, 2);
+    assert!((gears.exact_machines - 1.5).abs() < 1e-9);
+    assert_eq!(order.imports.len(), 1);
+    assert_eq!(order.imports[0].item, Item::IronOre);
+
+    let csv = order.to_csv();
+    assert!(csv.starts_with("producer,recipe,machines,exact_machines,inputs,outputs\n"));
+    assert!(csv.contains("AssemblingMachine,Gear,2,1.5,IronIngot:90,Gear:90\n"));
+    let markdown = order.to_markdown();
+    assert!(markdown.contains("## Smelter"));
+    assert!(markdown.contains("- IronOre: 90.00/min"));
+    let json: serde_json::Value = serde_json::from_str(&order.to_json().unwrap()).unwrap();
+    assert_eq!(json["groups"][0]["lines"][0]["machines"], 2);
+}
+
+#[test]
+fn counts_extra_products() {
+    let info = AllDSPInfo::generate();
+    let mut calculator = Calculator::new(&info);
+    calculator.raw_items[Item::IronIngot] = true;
+    calculator.extra_products = 0.25;
+    let plan = calculator.plan(&[ItemStack {
+        item: Item::Gear,
+        count: 100.0,
+    }]);
+    let order = BuildOrder::new(&info, &plan);
+    let gears = &order.groups[0].lines[0];
+    // 80 crafts make 100 gears out of 80 ingots
+    assert!((gears.inputs[0].count - 80.0).abs() < 1e-9);
+    assert!((gears.outputs[0].count - 100.0).abs() < 1e-9);
+}
@@ -0,0 +1,54 @@
+use generator::{
+    buildings::{describe, PowerRole, Slot},
+    generator::AllDSPInfo,
+    items::Item,
+    producers::Producer,
+    recipes::Recipe,
+};
+
+#[test]
+fn building_stats() {
+    let smelter = Item::Smelter.building().unwrap();
+    assert_eq!(smelter.producer, Some(Producer::Smelter));
+    assert_eq!(smelter.size, [3, 3]);
+    assert_eq!(smelter.power, PowerRole::Consumer);
+    assert!(smelter.slots.is_empty());
+
+    let ils = Item::InterstellarLogisticsStation.building().unwrap();
+    assert_eq!(ils.size, [8, 8]);
+    assert_eq!(
+        ils.slots,
+        vec![Slot::Drones(10), Slot::Vessels(10), Slot::Warpers]
+    );
+    assert_eq!(
+        Item::PlanetaryLogisticsStation.building().unwrap().slots,
+        vec![Slot::Drones(10)]
+    );
+    assert_eq!(
+        Item::ThermalPowerStation.building().unwrap().slots,
+        vec![Slot::Fuel]
+    );
+    assert!(Item::Gear.building().is_none());
+
+    assert_eq!(
+        Producer::AssemblingMachine.buildings(),
+        vec![
+            Item::AssemblingMachineMK1,
+            Item::AssemblingMachineMK2,
+            Item::AssemblingMachineMK3
+        ]
+    );
+    assert_eq!(Item::AssemblingMachineMK3.building().unwrap().speed, 1.5);
+}
+
+#[test]
+fn describing() {
+    let info = AllDSPInfo::generate();
+    let smelter = describe(&info, Item::Smelter).unwrap();
+    assert_eq!(smelter.made_by, &[Recipe::Smelter]);
+    assert!(smelter.runs.contains(&Recipe::IronSmelting));
+    assert!(!smelter.runs.contains(&Recipe::Gear));
+    // Belts don't run anything
+    assert!(describe(&info, Item::ConveyorMK1).unwrap().runs.is_empty());
+    assert!(describe(&info, Item::IronOre).is_none());
+}
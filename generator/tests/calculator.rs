@@ -0,0 +1,79 @@
+use generator::{
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    recipes::Recipe,
+};
+
+#[test]
+fn gear_chain() {
+    let info = AllDSPInfo::generate();
+    let mut calculator = Calculator::new(&info);
+    let targets = [ItemStack {
+        item: Item::Gear,
+        count: 120.0,
+    }];
+
+    let plan = calculator.plan(&targets);
+    let machines = |recipe| plan.line(recipe).unwrap().machines;
+    // Both take a second, so each machine does 60 a minute
+    assert!((machines(Recipe::Gear) - 2.0).abs() < 1e-9);
+    assert!((machines(Recipe::IronSmelting) - 2.0).abs() < 1e-9);
+    // 30 ore a minute off each vein
+    assert!((machines(Recipe::IronMining) - 4.0).abs() < 1e-9);
+    assert_eq!(plan.mining.len(), 1);
+    assert!((plan.mining[0].veins - 4.0).abs() < 1e-9);
+    assert!(plan.imports.values().all(|&count| count == 0.0));
+
+    // Covering more veins means fewer miners, but the same veins
+    calculator.mining.veins_per_miner = 6.0;
+    let plan = calculator.plan(&targets);
+    let mining = plan.mining[0];
+    assert_eq!(mining.item, Item::IronOre);
+    assert!((mining.per_minute - 120.0).abs() < 1e-9);
+    assert!((mining.machines - 120.0 / 180.0).abs() < 1e-9);
+    assert!((mining.veins - 4.0).abs() < 1e-9);
+
+    // And nothing gets mined at all if ingots come from somewhere else
+    calculator.raw_items[Item::IronIngot] = true;
+    let plan = calculator.plan(&targets);
+    assert!(plan.line(Recipe::IronMining).is_none());
+    assert!((plan.imports[Item::IronIngot] - 120.0).abs() < 1e-9);
+}
+
+#[test]
+fn accumulator_loop() {
+    let info = AllDSPInfo::generate();
+    let mut calculator = Calculator::new(&info);
+    // Charging needs empties, and discharging makes them back out of full ones
+    calculator.recipe_choices[Item::Accumulator] = Some(Recipe::DischargeAccumulator);
+    let plan = calculator.plan(&[ItemStack {
+        item: Item::FullAccumulator,
+        count: 15.0,
+    }]);
+    let line = plan.line(Recipe::ChargeAccumulator).unwrap();
+    assert!((line.crafts_per_minute - 15.0).abs() < 1e-9);
+    let line = plan.line(Recipe::DischargeAccumulator).unwrap();
+    assert!((line.crafts_per_minute - 15.0).abs() < 1e-9);
+    // The loop gets cut where it comes back around, so the full ones come in from outside
+    assert!((plan.imports[Item::FullAccumulator] - 15.0).abs() < 1e-9);
+    assert!(plan.line(Recipe::Accumulator).is_none());
+}
+
+#[test]
+fn ignores_choices_that_make_nothing() {
+    let info = AllDSPInfo::generate();
+    let mut calculator = Calculator::new(&info);
+    // Gears don't make any ingots, so this would divide by zero
+    calculator.recipe_choices[Item::IronIngot] = Some(Recipe::Gear);
+    assert_eq!(
+        calculator.recipe_for(Item::IronIngot),
+        Some(Recipe::IronSmelting)
+    );
+    let plan = calculator.plan(&[ItemStack {
+        item: Item::Gear,
+        count: 60.0,
+    }]);
+    assert!(plan.lines.iter().all(|line| line.machines.is_finite()));
+    assert!((plan.line(Recipe::IronSmelting).unwrap().machines - 1.0).abs() < 1e-9);
+}
@@ -0,0 +1,51 @@
+use generator::{calculator::Calculator, dyson::DysonPlanner, generator::AllDSPInfo, items::Item};
+
+#[test]
+fn swarm_and_sphere() {
+    let info = AllDSPInfo::generate();
+    let calculator = Calculator::new(&info);
+    let mut planner = DysonPlanner::default();
+
+    // 1 GW off 36 kW sails that last 90 minutes
+    let swarm = planner.plan(&calculator, 1.0).unwrap();
+    assert!((swarm.swarm_sails - 1e9 / 36e3).abs() < 1e-6);
+    assert_eq!(swarm.sphere_cells, 0.0);
+    assert!((swarm.sails_per_second - swarm.swarm_sails / 5400.0).abs() < 1e-9);
+    assert_eq!(swarm.rockets_per_minute, 0.0);
+    // Sails get made, not brought in
+    assert_eq!(swarm.production.imports[Item::SolarSail], 0.0);
+
+    planner.sphere_share = 1.0;
+    let sphere = planner.plan(&calculator, 1.0).unwrap();
+    assert_eq!(sphere.swarm_sails, 0.0);
+    assert!((sphere.sphere_rockets - sphere.sphere_cells / 2.0).abs() < 1e-9);
+    assert!((sphere.rockets_per_minute - sphere.sphere_rockets / 60.0).abs() < 1e-9);
+    assert!(sphere.silos > 0.0);
+}
+
+#[test]
+fn rejects_bad_settings() {
+    let info = AllDSPInfo::generate();
+    let calculator = Calculator::new(&info);
+    for bad in [
+        DysonPlanner {
+            sphere_share: 1.5,
+            ..Default::default()
+        },
+        DysonPlanner {
+            sphere_share: -0.1,
+            ..Default::default()
+        },
+        DysonPlanner {
+            build_time: 0.0,
+            ..Default::default()
+        },
+        DysonPlanner {
+            luminosity: 0.0,
+            ..Default::default()
+        },
+    ] {
+        assert!(bad.plan(&calculator, 1.0).is_err(), "{:?}", bad);
+    }
+    assert!(DysonPlanner::default().plan(&calculator, -1.0).is_err());
+}
@@ -0,0 +1,75 @@
+use generator::{
+    encode::{self, Format},
+    generator::AllDSPInfo,
+    schema,
+};
+
+use serde_json::Value;
+
+#[test]
+fn binary_formats_round_trip() {
+    let info = AllDSPInfo::generate();
+    let json = serde_json::to_value(&info).unwrap();
+
+    let msgpack: Value =
+        rmp_serde::from_slice(&encode::encode(&info, Format::MessagePack).unwrap()).unwrap();
+    let cbor: Value =
+        ciborium::de::from_reader(&encode::encode(&info, Format::Cbor).unwrap()[..]).unwrap();
+    assert_eq!(msgpack, cbor);
+
+    // Same fields, but the enum maps are arrays
+    for (field, value) in json.as_object().unwrap() {
+        match value {
+            Value::Object(kinds) if field == "names" => {
+                for (kind, names) in kinds {
+                    assert_eq!(
+                        msgpack[field][kind].as_array().unwrap().len(),
+                        names.as_object().unwrap().len()
+                    );
+                }
+            }
+            Value::Object(map) => {
+                assert_eq!(msgpack[field].as_array().unwrap().len(), map.len())
+            }
+            _ => assert_eq!(&msgpack[field], value),
+        }
+    }
+    assert_eq!(
+        msgpack["recipes"][0]["recipe"],
+        json["recipes"]["IronSmelting"]["recipe"]
+    );
+}
+
+#[test]
+fn indexed_has_no_names() {
+    let info = AllDSPInfo::generate();
+    let indexed = encode::to_indexed(&info).unwrap();
+
+    fn check(value: &Value) {
+        match value {
+            Value::Object(_) => panic!("found an object: {}", value),
+            Value::String(s) => panic!("found a string: {}", s),
+            Value::Array(elements) => elements.iter().for_each(check),
+            _ => {}
+        }
+    }
+    // Display names are the only strings that should be left
+    let schema = serde_json::to_value(schema::schema()).unwrap();
+    let names_at = schema["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .position(|field| field == "names")
+        .unwrap();
+    for (idx, field) in indexed.as_array().unwrap().iter().enumerate() {
+        if idx != names_at {
+            check(field);
+        }
+    }
+    assert_eq!(indexed[names_at][0][0], "Iron Ore");
+
+    let sizes = encode::size_report(&info).unwrap();
+    let size = |format| sizes.iter().find(|(f, _)| *f == format).unwrap().1;
+    assert!(size(Format::IndexedJson) < size(Format::CompactJson));
+    assert!(size(Format::CompactJson) < size(Format::PrettyJson));
+}
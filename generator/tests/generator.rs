@@ -0,0 +1,11 @@
+use generator::generator::AllDSPInfo;
+
+#[test]
+fn every_item_has_a_recipe() {
+    let info = AllDSPInfo::generate();
+    assert_eq!(
+        info.unproducible_items(),
+        vec![],
+        "Nothing makes these items"
+    );
+}
@@ -0,0 +1,50 @@
+use generator::{items::Item, recipes::Recipe, technologies::Technology};
+
+use strum::IntoEnumIterator;
+
+use std::collections::HashSet;
+
+#[test]
+fn recipe_ids() {
+    // Mining and everything after it aren't real recipes in the game
+    let made_up: Vec<_> = Recipe::iter()
+        .skip_while(|&recipe| recipe != Recipe::IronMining)
+        .collect();
+    let mut seen = HashSet::new();
+    for recipe in Recipe::iter() {
+        let id = match recipe.game_id() {
+            Some(id) => id,
+            None => {
+                assert!(made_up.contains(&recipe), "{:?} has no game ID", recipe);
+                continue;
+            }
+        };
+        assert!(!made_up.contains(&recipe), "{:?} isn't in the game", recipe);
+        assert!(seen.insert(id), "{:?} shares ID {}", recipe, id);
+        assert_eq!(Recipe::from_game_id(id), Some(recipe));
+    }
+    assert_eq!(Recipe::from_game_id(5), Some(Recipe::Gear));
+}
+
+#[test]
+fn technology_ids() {
+    let mut seen = HashSet::new();
+    for tech in Technology::iter() {
+        let id = tech.game_id();
+        assert!(seen.insert(id), "{:?} shares ID {}", tech, id);
+        assert_eq!(Technology::from_game_id(id), Some(tech));
+    }
+}
+
+#[test]
+fn item_ids() {
+    let mut seen = HashSet::new();
+    for item in Item::iter() {
+        assert!(
+            seen.insert(item.game_id()),
+            "{:?} shares ID {}",
+            item,
+            item.game_id()
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use generator::{
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    logistics::{belt_throughput, Belt, LogisticsModel, Sorter},
+    recipes::Recipe,
+};
+
+#[test]
+fn belts_and_sorters() {
+    assert_eq!(Belt::from_item(Item::ConveyorMK2), Some(Belt::MK2));
+    assert_eq!(Belt::from_item(Item::Gear), None);
+    assert_eq!(belt_throughput(Item::ConveyorMK3), Some(30.0));
+    assert_eq!(Sorter::from_item(Item::SorterMK3), Some(Sorter::MK3));
+    assert_eq!(Sorter::MK2.throughput(2), 1.5);
+    assert_eq!(Sorter::MK1.throughput(0), Sorter::MK1.throughput(1));
+}
+
+#[test]
+fn gear_flows() {
+    let info = AllDSPInfo::generate();
+    let plan = Calculator::new(&info).plan(&[ItemStack {
+        item: Item::Gear,
+        count: 120.0,
+    }]);
+    let mut model = LogisticsModel::default();
+    let flows = model.flows(&info, &plan);
+    let ingots_in = flows
+        .iter()
+        .find(|flow| flow.recipe == Some(Recipe::Gear) && flow.item == Item::IronIngot)
+        .unwrap();
+    // 2 ingots a second is a third of a MK.I belt, and each of the 2 machines
+    // needs 1 a second, which one MK.I sorter covers
+    assert!((ingots_in.belts - 2.0 / 6.0).abs() < 1e-9);
+    assert_eq!(ingots_in.sorters, 2.0);
+    assert_eq!(ingots_in.vessels, 0.0);
+
+    model.sorter_distance = 2;
+    let flows = model.flows(&info, &plan);
+    let ingots_in = flows
+        .iter()
+        .find(|flow| flow.recipe == Some(Recipe::Gear) && flow.item == Item::IronIngot)
+        .unwrap();
+    assert_eq!(ingots_in.sorters, 4.0);
+
+    // The extra gears from proliferating have to go somewhere too
+    let mut calculator = Calculator::new(&info);
+    calculator.extra_products = 0.25;
+    let plan = calculator.plan(&[ItemStack {
+        item: Item::Gear,
+        count: 120.0,
+    }]);
+    let flows = model.flows(&info, &plan);
+    let flow = |item| {
+        flows
+            .iter()
+            .find(|flow| flow.recipe == Some(Recipe::Gear) && flow.item == item)
+            .unwrap()
+            .per_minute
+    };
+    assert!((flow(Item::IronIngot) - 96.0).abs() < 1e-9);
+    assert!((flow(Item::Gear) - 120.0).abs() < 1e-9);
+
+    // A 200 second round trip for 200 items, at 1 a second
+    assert!((LogisticsModel::default().vessels_for(60.0) - 1.0).abs() < 1e-9);
+}
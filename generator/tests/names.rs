@@ -0,0 +1,70 @@
+use generator::{
+    generator::AllDSPInfo,
+    items::Item,
+    names::{self, Named},
+    producers::Producer,
+    recipes::Recipe,
+    technologies::Technology,
+};
+
+#[test]
+fn resolves_names_and_aliases() {
+    assert_eq!("em matrix".parse(), Ok(Item::ElectromagneticMatrix));
+    assert_eq!("Blue Science".parse(), Ok(Item::ElectromagneticMatrix));
+    assert_eq!("ILS".parse(), Ok(Item::InterstellarLogisticsStation));
+    assert_eq!("conveyor_mk2".parse(), Ok(Item::ConveyorMK2));
+    assert_eq!("Conveyor MK.II".parse(), Ok(Item::ConveyorMK2));
+    assert_eq!("x-ray cracking".parse(), Ok(Recipe::XRayCracking));
+    assert_eq!("red science".parse(), Ok(Recipe::EnergyMatrix));
+    assert_eq!("gravity matrix".parse(), Ok(Technology::GravityMatrix));
+    assert_eq!("assembler".parse(), Ok(Producer::AssemblingMachine));
+}
+
+#[test]
+fn suggests_on_a_miss() {
+    let err = "titanim ingot".parse::<Item>().unwrap_err();
+    assert_eq!(err.kind, "item");
+    assert_eq!(err.suggestions[0], "Titanium Ingot");
+
+    let err = "graph".parse::<Item>().unwrap_err();
+    assert!(err.suggestions.contains(&"Graphene".to_string()));
+    assert!(err.to_string().contains("did you mean"));
+}
+
+fn no_clashes<T: Named + PartialEq>() {
+    for a in T::iter() {
+        for b in T::iter() {
+            if a != b {
+                for name in a.names() {
+                    assert!(
+                        !b.names().contains(&name),
+                        "{:?} and {:?} are both called {}",
+                        a,
+                        b,
+                        name
+                    );
+                }
+            }
+        }
+        assert_eq!(names::resolve::<T>(&a.to_string()).ok(), Some(a));
+    }
+}
+
+#[test]
+fn every_name_is_unique() {
+    no_clashes::<Item>();
+    no_clashes::<Recipe>();
+    no_clashes::<Technology>();
+    no_clashes::<Producer>();
+}
+
+#[test]
+fn exported_names() {
+    let info = AllDSPInfo::generate();
+    assert_eq!(info.names.items.0[Item::ConveyorMK2], "Conveyor MK.II");
+    assert_eq!(info.names.recipes.0[Recipe::XRayCracking], "X-Ray Cracking");
+    assert_eq!(
+        info.names.producers.0[Producer::EnergyExchanger],
+        "Energy Exchanger"
+    );
+}
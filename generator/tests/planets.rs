@@ -0,0 +1,102 @@
+use generator::{
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    logistics::LogisticsModel,
+    planets::{allocate, Planet},
+    recipes::Recipe,
+};
+
+fn electromagnets(info: &AllDSPInfo) -> generator::calculator::ProductionPlan {
+    Calculator::new(info).plan(&[ItemStack {
+        item: Item::Electromagnet,
+        count: 60.0,
+    }])
+}
+
+#[test]
+fn keeps_lines_near_their_ingredients() {
+    let info = AllDSPInfo::generate();
+    let plan = electromagnets(&info);
+
+    let mut iron = Planet::new("Iron world");
+    iron.resources[Item::IronOre] = 1000.0;
+    let mut copper = Planet::new("Copper world");
+    copper.resources[Item::CopperOre] = 1000.0;
+    let allocation = allocate(&info, &plan, &[iron, copper], &LogisticsModel::default()).unwrap();
+
+    let recipes = |idx: usize| -> Vec<Recipe> {
+        allocation.planets[idx]
+            .lines
+            .iter()
+            .map(|line| line.recipe)
+            .collect()
+    };
+    // Magnets outweigh copper two to one, so the assembling happens with the iron
+    assert!(recipes(0).contains(&Recipe::MagnetSmelting));
+    assert!(recipes(0).contains(&Recipe::Electromagnet));
+    assert!(recipes(1).contains(&Recipe::CopperSmelting));
+    assert!(!recipes(1).contains(&Recipe::Electromagnet));
+
+    // So the only thing going between them is copper ingots
+    assert_eq!(allocation.flows.len(), 1);
+    let flow = allocation.flows[0];
+    assert_eq!(flow.item, Item::CopperIngot);
+    assert_eq!((flow.from, flow.to), (Some(1), 0));
+    assert!((flow.per_minute - 30.0).abs() < 1e-6);
+    assert!(flow.vessels > 0.0);
+    assert!((allocation.interstellar_per_minute() - 30.0).abs() < 1e-6);
+}
+
+#[test]
+fn respects_limits() {
+    let info = AllDSPInfo::generate();
+    let plan = electromagnets(&info);
+
+    let mut small = Planet::new("Small");
+    small.resources[Item::IronOre] = 1000.0;
+    small.resources[Item::CopperOre] = 1000.0;
+    small.max_machines = 2.0;
+    let mut big = Planet::new("Big");
+    big.resources = small.resources;
+    let allocation = allocate(
+        &info,
+        &plan,
+        &[small.clone(), big],
+        &LogisticsModel::default(),
+    )
+    .unwrap();
+    assert!(allocation.planets[0].machines <= 2.0 + 1e-6);
+    assert!(allocation.planets[0].power > 0.0);
+    let total: f64 = allocation.planets.iter().map(|p| p.machines).sum();
+    let planned: f64 = plan.lines.iter().map(|line| line.machines).sum();
+    assert!((total - planned).abs() < 1e-6);
+
+    // Nowhere to mine anything
+    assert!(allocate(
+        &info,
+        &plan,
+        &[Planet::new("Barren")],
+        &LogisticsModel::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn skips_empty_lines() {
+    let info = AllDSPInfo::generate();
+    let mut plan = electromagnets(&info);
+    // A mining line that doesn't run would divide the planet's ore by 0
+    for line in plan.lines.iter_mut() {
+        if line.recipe == Recipe::IronMining {
+            line.crafts_per_minute = 0.0;
+            line.machines = 0.0;
+        }
+    }
+    let mut copper = Planet::new("Copper world");
+    copper.resources[Item::CopperOre] = 1000.0;
+    let allocation = allocate(&info, &plan, &[copper], &LogisticsModel::default()).unwrap();
+    let lines = &allocation.planets[0].lines;
+    assert!(lines.iter().all(|line| line.recipe != Recipe::IronMining));
+    assert!(lines.iter().any(|line| line.recipe == Recipe::CopperMining));
+}
@@ -0,0 +1,87 @@
+use generator::{
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::Item,
+    power::{
+        compatible_fuels, fuel_energy, fuel_per_minute, generator_output, plan_fuel,
+        power_transport,
+    },
+};
+
+use strum::IntoEnumIterator;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[test]
+fn fuels_and_generators() {
+    assert_eq!(fuel_energy(Item::CoalOre), Some(2.7e6));
+    assert_eq!(fuel_energy(Item::DeuteronFuelRod), Some(600e6));
+    assert_eq!(fuel_energy(Item::Hydrogen), Some(9e6));
+    assert_eq!(fuel_energy(Item::IronOre), None);
+    assert_eq!(generator_output(Item::ThermalPowerStation), Some(2.16e6));
+    assert_eq!(generator_output(Item::ArtificialStar), Some(72e6));
+    assert_eq!(generator_output(Item::WindTurbine), None);
+
+    // Every fuel a generator takes has to be worth something
+    for generator in [
+        Item::ThermalPowerStation,
+        Item::MiniFusionPowerStation,
+        Item::ArtificialStar,
+    ] {
+        assert!(!compatible_fuels(generator).is_empty());
+        for &fuel in compatible_fuels(generator) {
+            assert!(fuel_energy(fuel).is_some(), "{} has no energy", fuel);
+        }
+    }
+    // And everything that burns goes in some generator
+    for fuel in Item::iter().filter(|&item| fuel_energy(item).is_some()) {
+        assert!(
+            [
+                Item::ThermalPowerStation,
+                Item::MiniFusionPowerStation,
+                Item::ArtificialStar,
+            ]
+            .iter()
+            .any(|&generator| compatible_fuels(generator).contains(&fuel)),
+            "nothing burns {}",
+            fuel
+        );
+    }
+}
+
+#[test]
+fn burning_coal() {
+    // 21.6 MW is 21.6 MJ a second, so 8 coal a second
+    assert!(close(
+        fuel_per_minute(Item::ThermalPowerStation, Item::CoalOre, 21.6).unwrap(),
+        480.0
+    ));
+    assert_eq!(
+        fuel_per_minute(Item::ArtificialStar, Item::CoalOre, 21.6),
+        None
+    );
+
+    let info = AllDSPInfo::generate();
+    let plan = plan_fuel(
+        &Calculator::new(&info),
+        Item::ThermalPowerStation,
+        Item::CoalOre,
+        21.6,
+    )
+    .unwrap();
+    assert!(close(plan.generators, 10.0));
+    assert!(close(
+        plan.production.raw_inputs(&info)[Item::CoalOre],
+        480.0
+    ));
+}
+
+#[test]
+fn moving_power() {
+    // 45 MW fills one exchanger and a 180 MJ accumulator every 4 seconds
+    let transport = power_transport(45.0);
+    assert!(close(transport.exchangers, 1.0));
+    assert!(close(transport.accumulators_per_minute, 15.0));
+}
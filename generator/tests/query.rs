@@ -0,0 +1,59 @@
+use generator::{
+    generator::AllDSPInfo,
+    query::{Comparison, Filter, Query},
+    recipes::Recipe,
+    technologies::Technology,
+};
+
+fn run(info: &AllDSPInfo, query: &str) -> Vec<Recipe> {
+    query.parse::<Query>().unwrap().run(info)
+}
+
+#[test]
+fn parses_terms() {
+    let query: Query =
+        "produces:Graphene -made_in:chemicalplant unlocked_by<=Processor time>=2.5 wood"
+            .parse()
+            .unwrap();
+    assert_eq!(query.terms.len(), 5);
+    assert!(query.terms[1].negated);
+    assert_eq!(
+        query.terms[2].filter,
+        Filter::UnlockedBy(Comparison::LessOrEqual, Technology::Processor)
+    );
+    assert_eq!(query.terms[4].filter, Filter::Name("wood".to_string()));
+
+    assert!("colour:blue".parse::<Query>().is_err());
+    assert!("uses:NotAnItem".parse::<Query>().is_err());
+    assert!("uses>IronOre".parse::<Query>().is_err());
+    assert!("time>soon".parse::<Query>().is_err());
+}
+
+#[test]
+fn finds_recipes() {
+    let info = AllDSPInfo::generate();
+    let run = |query| run(&info, query);
+    // Carbon nanotubes are the only chemical plant recipe with titanium, and they take exactly 4 seconds
+    assert_eq!(
+        run("uses:TitaniumIngot made_in:ChemicalPlant"),
+        vec![Recipe::CarbonNanotubeFromGraphene]
+    );
+    assert!(run("uses:TitaniumIngot made_in:ChemicalPlant time>4").is_empty());
+    assert_eq!(
+        run("uses:TitaniumIngot made_in:ChemicalPlant time>=4"),
+        vec![Recipe::CarbonNanotubeFromGraphene]
+    );
+    assert_eq!(
+        run("uses:TitaniumIngot made_in:Smelter time>4"),
+        vec![Recipe::TitaniumAlloy]
+    );
+
+    let early_graphene = run("produces:Graphene unlocked_by<=AppliedSuperconductor");
+    assert!(early_graphene.contains(&Recipe::GrapheneFromGraphiteAndSulfuric));
+    assert!(run("produces:Graphene unlocked_by<AppliedSuperconductor").is_empty());
+
+    for recipe in run("uses:IronIngot -handcraftable:true") {
+        assert!(!info.recipes.0[recipe].handcraftable);
+    }
+    assert!(run("smelting").contains(&Recipe::IronSmelting));
+}
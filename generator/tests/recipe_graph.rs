@@ -0,0 +1,76 @@
+use generator::{
+    generator::AllDSPInfo,
+    items::Item,
+    recipe_graph::{GraphOptions, RecipeGraph},
+};
+
+#[test]
+fn ancestors() {
+    let info = AllDSPInfo::generate();
+    let dot = RecipeGraph::new(
+        &info,
+        GraphOptions {
+            ancestors_of: Some(Item::Gear),
+            ..Default::default()
+        },
+    )
+    .to_dot();
+    assert!(dot.contains("recipe_Gear [label=\"Gear\", shape=box];"));
+    assert!(dot.contains("recipe_IronSmelting -> item_IronIngot [label=\"1\"];"));
+    assert!(dot.contains("item_IronOre"));
+    assert!(!dot.contains("recipe_CopperSmelting"));
+    assert!(!dot.contains("buildings"));
+}
+
+#[test]
+fn collapsed_buildings() {
+    let info = AllDSPInfo::generate();
+    let graph = RecipeGraph::new(
+        &info,
+        GraphOptions {
+            collapse_buildings: true,
+            ..Default::default()
+        },
+    );
+    let dot = graph.to_dot();
+    assert!(dot.contains("buildings [label=\"Buildings\", shape=box3d];"));
+    assert!(dot.contains("item_Gear -> buildings;"));
+    assert_eq!(dot.matches("item_Gear -> buildings;").count(), 1);
+    assert!(!dot.contains("recipe_TeslaTower"));
+    assert!(!dot.contains("item_TeslaTower"));
+    assert!(!dot.contains("item_SorterMK1"));
+    // Accumulators get charged in an exchanger, which isn't building anything
+    assert!(dot.contains("recipe_ChargeAccumulator "));
+    assert!(dot.contains("recipe_DischargeAccumulator "));
+    assert!(dot.contains("item_Accumulator -> recipe_ChargeAccumulator"));
+    assert!(dot.contains("recipe_Gear "));
+    assert!(dot.contains("recipe_IronSmelting "));
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.starts_with("graph LR\n"));
+    assert!(mermaid.contains("    buildings[[Buildings]]\n"));
+}
+
+#[test]
+fn colors() {
+    let info = AllDSPInfo::generate();
+    let graph = RecipeGraph::new(
+        &info,
+        GraphOptions {
+            ancestors_of: Some(Item::Gear),
+            color_by_producer: true,
+            ..Default::default()
+        },
+    );
+    let dot = graph.to_dot();
+    assert!(dot
+        .contains("recipe_Gear [label=\"Gear\", shape=box, style=filled, fillcolor=\"#8dd3c7\"];"));
+    assert!(dot.contains(
+        "recipe_IronSmelting [label=\"IronSmelting\", shape=box, style=filled, fillcolor=\"#ffffb3\"];"
+    ));
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.contains("    classDef AssemblingMachine fill:#8dd3c7\n"));
+    assert!(mermaid.contains("    class recipe_Gear AssemblingMachine\n"));
+    assert!(!mermaid.contains("classDef OilRefinery"));
+}
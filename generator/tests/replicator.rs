@@ -0,0 +1,75 @@
+use generator::{
+    generator::AllDSPInfo,
+    items::Item,
+    recipes::Recipe,
+    replicator::{HandcraftPlan, Replicator},
+};
+
+#[test]
+fn handcraftable() {
+    let info = AllDSPInfo::generate();
+    let replicator = Replicator::new(&info);
+    assert_eq!(replicator.recipe_for(Item::Gear), Some(Recipe::Gear));
+    assert_eq!(
+        replicator.recipe_for(Item::IronIngot),
+        Some(Recipe::IronSmelting)
+    );
+    // Ore has to be mined, and the replicator can't do oil or chemistry
+    assert_eq!(replicator.recipe_for(Item::IronOre), None);
+    assert_eq!(replicator.recipe_for(Item::RefinedOil), None);
+    assert_eq!(replicator.recipe_for(Item::Plastic), None);
+}
+
+#[test]
+fn gears_from_scratch() {
+    let info = AllDSPInfo::generate();
+    let mut replicator = Replicator::new(&info);
+    let plan = replicator.plan(Item::Gear, 10).unwrap();
+    let crafts = |plan: &HandcraftPlan, recipe| {
+        plan.crafts
+            .iter()
+            .find(|craft| craft.recipe == recipe)
+            .map_or(0, |craft| craft.crafts)
+    };
+    assert_eq!(crafts(&plan, Recipe::Gear), 10);
+    assert_eq!(crafts(&plan, Recipe::IronSmelting), 10);
+    assert_eq!(plan.missing[Item::IronOre], 10);
+    // A second a craft, one after the other
+    assert_eq!(plan.total_time, 20.0);
+
+    // Whatever's in storage gets used first, and a faster replicator takes less time
+    replicator.storage[Item::IronIngot] = 4;
+    replicator.speed = 2.0;
+    let plan = replicator.plan(Item::Gear, 10).unwrap();
+    assert_eq!(plan.from_storage[Item::IronIngot], 4);
+    assert_eq!(crafts(&plan, Recipe::IronSmelting), 6);
+    assert_eq!(plan.missing[Item::IronOre], 6);
+    assert_eq!(plan.total_time, 8.0);
+}
+
+#[test]
+fn whole_crafts() {
+    let info = AllDSPInfo::generate();
+    let plan = Replicator::new(&info).plan(Item::Electromagnet, 3).unwrap();
+    let coils = plan
+        .crafts
+        .iter()
+        .find(|craft| craft.recipe == Recipe::Electromagnet)
+        .unwrap();
+    // Each craft makes 2, so 3 takes 2 crafts and leaves 1 over
+    assert_eq!(coils.crafts, 2);
+    assert_eq!(coils.time, 2.0);
+    assert_eq!(plan.leftovers[Item::Electromagnet], 1);
+}
+
+#[test]
+fn fractional_stacks() {
+    let mut info = AllDSPInfo::generate();
+    info.recipes.0[Recipe::IronSmelting].ingredients[0].count = 1.5;
+    let replicator = Replicator::new(&info);
+    assert!(replicator.plan(Item::Gear, 10).is_err());
+    // Nothing about it gets used if there's enough in storage
+    let mut replicator = Replicator::new(&info);
+    replicator.storage[Item::IronIngot] = 10;
+    assert!(replicator.plan(Item::Gear, 10).is_ok());
+}
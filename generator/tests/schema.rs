@@ -0,0 +1,83 @@
+use generator::{
+    generator::AllDSPInfo,
+    schema::{self, SCHEMA_VERSION},
+};
+
+use serde_json::Value;
+
+use std::fs;
+
+#[test]
+fn schema_matches_published_version() {
+    let path = schema::schema_path(SCHEMA_VERSION);
+    let published = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "No schema published at {} yet; run the generator to write it",
+            path.display()
+        )
+    });
+    let published: Value = serde_json::from_str(&published).unwrap();
+    let current = serde_json::to_value(schema::schema()).unwrap();
+    assert!(
+        published == current,
+        "AllDSPInfo changed shape without bumping SCHEMA_VERSION (still {})",
+        SCHEMA_VERSION
+    );
+}
+
+#[test]
+fn schema_has_id_and_version() {
+    let schema = serde_json::to_value(schema::schema()).unwrap();
+    assert_eq!(schema["$id"], schema::schema_id(SCHEMA_VERSION));
+    assert!(schema["required"]
+        .as_array()
+        .unwrap()
+        .contains(&"schema_version".into()));
+
+    let info = serde_json::to_value(AllDSPInfo::generate()).unwrap();
+    assert_eq!(info["schema_version"], SCHEMA_VERSION);
+}
+
+#[test]
+fn migrates_unversioned_json() {
+    let current = serde_json::to_value(AllDSPInfo::generate()).unwrap();
+
+    // Roughly what dsp.json looked like before it had a version
+    let mut old = current.clone();
+    let fields = old.as_object_mut().unwrap();
+    for field in ["schema_version", "tech_layout", "buildings", "fuel_energy"].iter() {
+        fields.remove(*field);
+    }
+    for entry in fields["tech_tree"].as_object_mut().unwrap().values_mut() {
+        let entry = entry.as_object_mut().unwrap();
+        for field in [
+            "all_prereqs",
+            "all_postreqs",
+            "depth",
+            "steps_to_completion",
+        ]
+        .iter()
+        {
+            entry.remove(*field);
+        }
+    }
+
+    assert_eq!(schema::migrate(old).unwrap(), current);
+    assert_eq!(schema::migrate(current.clone()).unwrap(), current);
+}
+
+#[test]
+fn migrates_version_1() {
+    let current = serde_json::to_value(AllDSPInfo::generate()).unwrap();
+    let mut old = current.clone();
+    old.as_object_mut().unwrap().remove("names");
+    old["schema_version"] = 1.into();
+    assert_eq!(schema::migrate(old).unwrap(), current);
+}
+
+#[test]
+fn refuses_newer_versions() {
+    let mut doc = serde_json::to_value(AllDSPInfo::generate()).unwrap();
+    doc["schema_version"] = (SCHEMA_VERSION + 1).into();
+    assert!(schema::migrate(doc).is_err());
+}
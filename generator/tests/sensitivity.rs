@@ -0,0 +1,85 @@
+use generator::{
+    calculator::Calculator,
+    generator::AllDSPInfo,
+    items::{Item, ItemStack},
+    producers::Producer,
+    recipes::Recipe,
+    sensitivity::{analyze, changes_to_try, Change},
+};
+
+fn gears() -> [ItemStack; 1] {
+    [ItemStack {
+        item: Item::Gear,
+        count: 60.0,
+    }]
+}
+
+#[test]
+fn scenarios() {
+    let info = AllDSPInfo::generate();
+    let calculator = Calculator::new(&info);
+    let changes = [
+        Change::MachineTier(Item::AssemblingMachineMK3),
+        Change::RecipeTime(Recipe::Gear, 2.0),
+        Change::Proliferator(3),
+        Change::Substitute(Item::Graphene, Recipe::GrapheneFromFireIce),
+    ];
+    let report = analyze(&calculator, &gears(), &changes).unwrap();
+    let assemblers =
+        |idx: usize| report.scenarios[idx].outcome.machines[Producer::AssemblingMachine];
+    let base = report.base.machines[Producer::AssemblingMachine];
+    assert!((assemblers(0) - base / 1.5).abs() < 1e-6);
+    assert!((assemblers(1) - base * 2.0).abs() < 1e-6);
+    // Both the smelting and the gears get the bonus, but not the mining
+    assert!((report.scenarios[2].outcome.raw[Item::IronOre] - 60.0 / 1.25 / 1.25).abs() < 1e-6);
+    // Gears don't need graphene, so that doesn't change anything
+    assert_eq!(
+        report.scenarios[3].outcome.total_machines(),
+        report.base.total_machines()
+    );
+    let table = report.to_string();
+    assert!(table.contains("Proliferator level 3 *"));
+    assert!(table.contains("* Doesn't count the proliferator itself"));
+
+    let graphene = [ItemStack {
+        item: Item::Graphene,
+        count: 60.0,
+    }];
+    // Fire ice is the default, so try it the long way
+    let swap = [Change::Substitute(
+        Item::Graphene,
+        Recipe::GrapheneFromGraphiteAndSulfuric,
+    )];
+    let report = analyze(&calculator, &graphene, &swap).unwrap();
+    assert!(report.base.raw[Item::FireIce] > 0.0);
+    assert_eq!(report.scenarios[0].outcome.raw[Item::FireIce], 0.0);
+
+    for bad in [
+        Change::Proliferator(4),
+        Change::MachineTier(Item::IronOre),
+        Change::Substitute(Item::Gear, Recipe::IronSmelting),
+        Change::RecipeTime(Recipe::Gear, 0.0),
+        Change::RecipeTime(Recipe::Gear, f64::INFINITY),
+        Change::RecipeTime(Recipe::Gear, f64::NAN),
+    ] {
+        assert!(analyze(&calculator, &gears(), &[bad]).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn suggestions() {
+    let info = AllDSPInfo::generate();
+    let calculator = Calculator::new(&info);
+    let changes = changes_to_try(&calculator, &gears());
+    assert!(!changes.contains(&Change::Proliferator(1)));
+    assert!(changes.contains(&Change::MachineTier(Item::AssemblingMachineMK3)));
+    assert!(!changes.contains(&Change::MachineTier(Item::AssemblingMachineMK1)));
+    assert!(changes.contains(&Change::RecipeTime(Recipe::Gear, 0.5)));
+    assert!(!changes.contains(&Change::RecipeTime(Recipe::IronMining, 0.5)));
+
+    let report = analyze(&calculator, &gears(), &changes).unwrap();
+    let ranked = report.ranked();
+    assert!(ranked
+        .windows(2)
+        .all(|pair| pair[0].outcome.total_machines() <= pair[1].outcome.total_machines()));
+}
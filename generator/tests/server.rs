@@ -0,0 +1,90 @@
+#![cfg(feature = "server")]
+
+use generator::{generator::AllDSPInfo, server};
+
+use serde_json::Value;
+use tiny_http::Server;
+
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    thread,
+};
+
+/// Start a server on some free port and get its address.
+fn start() -> SocketAddr {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    thread::spawn(move || server::serve(&AllDSPInfo::generate(), &server));
+    addr
+}
+
+/// The dumbest HTTP client that could possibly work.
+fn get(addr: SocketAddr, path: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, addr
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response[9..12].parse().unwrap();
+    let body = &response[response.find("\r\n\r\n").unwrap() + 4..];
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn answers_over_http() {
+    let addr = start();
+
+    let (status, body) = get(addr, "/items/blue%20science/produce");
+    assert_eq!(status, 200);
+    assert_eq!(body[0]["recipe"], "ElectromagneticMatrix");
+
+    let (status, body) = get(addr, "/techs/Processor");
+    assert_eq!(status, 200);
+    assert!(body["all_prereqs"]
+        .as_array()
+        .unwrap()
+        .contains(&"SemiconductorMaterial".into()));
+
+    let (status, body) = get(addr, "/rate/Gear?per_minute=120");
+    assert_eq!(status, 200);
+    assert!(body["lines"].as_array().unwrap().len() >= 2);
+
+    let (status, body) = get(addr, "/search?q=produces:Graphene");
+    assert_eq!(status, 200);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    let (status, body) = get(addr, "/items/titanim/consume");
+    assert_eq!(status, 404);
+    assert!(body["error"].as_str().unwrap().contains("did you mean"));
+}
+
+#[test]
+fn bill_of_materials() {
+    let info = AllDSPInfo::generate();
+    let (status, body) = server::handle(&info, "/bom/Gear?count=10");
+    assert_eq!(status, 200);
+    assert_eq!(body["raw"][0]["item"], "IronOre");
+    assert_eq!(body["raw"][0]["count"], 10.0);
+
+    assert_eq!(server::handle(&info, "/bom/Gear?count=lots").0, 400);
+    assert_eq!(server::handle(&info, "/nowhere").0, 404);
+}
+
+#[test]
+fn rejects_bad_numbers() {
+    let info = AllDSPInfo::generate();
+    for count in ["-1", "NaN", "inf", "-inf"] {
+        let (status, body) = server::handle(&info, &format!("/bom/Gear?count={}", count));
+        assert_eq!(status, 400, "{}", count);
+        assert!(body["error"].as_str().unwrap().contains(count));
+        let url = format!("/rate/Gear?per_minute={}", count);
+        assert_eq!(server::handle(&info, &url).0, 400, "{}", count);
+    }
+    assert_eq!(server::handle(&info, "/rate/Gear?per_minute=0").0, 200);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "sqlite")]
+
+use generator::{generator::AllDSPInfo, sqlite};
+
+use rusqlite::Connection;
+
+fn database() -> Connection {
+    let mut conn = Connection::open_in_memory().unwrap();
+    sqlite::write_to(&AllDSPInfo::generate(), &mut conn).unwrap();
+    conn
+}
+
+#[test]
+fn foreign_keys_hold() {
+    let conn = database();
+    let broken: i64 = conn
+        .query_row("SELECT COUNT(*) FROM pragma_foreign_key_check", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(broken, 0);
+}
+
+#[test]
+fn titanium_recipes_over_five_seconds() {
+    let conn = database();
+    let mut query = conn
+        .prepare(
+            "SELECT DISTINCT r.name FROM recipes r
+            JOIN recipe_ingredients ri ON ri.recipe_id = r.id
+            JOIN items i ON i.id = ri.item_id
+            WHERE i.name LIKE 'Titanium%' AND r.time > 5
+            ORDER BY r.name",
+        )
+        .unwrap();
+    let names: Vec<String> = query
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert!(names.contains(&"MiniFusionPowerStation".to_string()));
+    assert!(!names.contains(&"TitaniumSmelting".to_string()));
+}
@@ -0,0 +1,51 @@
+use generator::{tech_layout::TechLayout, technologies::Technology};
+
+use strum::IntoEnumIterator;
+
+#[test]
+fn layers() {
+    let layout = TechLayout::generate();
+    assert_eq!(layout.positions[Technology::DysonSphereProgram].layer, 0);
+    for tech in Technology::iter() {
+        assert_eq!(layout.positions[tech].layer, tech.depth());
+        for &pre in tech.prerequisites() {
+            assert!(
+                layout.positions[pre].layer < layout.positions[tech].layer,
+                "{:?} -> {:?}",
+                pre,
+                tech
+            );
+        }
+    }
+
+    // Every tech shows up exactly once
+    let count: usize = layout.layers.iter().map(Vec::len).sum();
+    assert_eq!(count, Technology::iter().count());
+    for (layer, techs) in layout.layers.iter().enumerate() {
+        for (column, &tech) in techs.iter().enumerate() {
+            assert_eq!(layout.positions[tech].layer as usize, layer);
+            assert_eq!(layout.positions[tech].column as usize, column);
+        }
+    }
+}
+
+#[test]
+fn fewer_crossings() {
+    let start = TechLayout::by_depth().crossings();
+    let swept = TechLayout::generate().crossings();
+    assert!(swept <= start, "{} crossings, up from {}", swept, start);
+}
+
+#[test]
+fn renders_every_tech() {
+    let layout = TechLayout::generate();
+    let svg = layout.to_svg();
+    let dot = layout.to_dot();
+    assert!(svg.starts_with("<svg "));
+    assert!(dot.starts_with("digraph tech_tree {"));
+    for tech in Technology::iter() {
+        assert!(svg.contains(&format!(">{:?}</text>", tech)), "{:?}", tech);
+        assert!(dot.contains(&format!(" {:?};", tech)), "{:?}", tech);
+    }
+    assert!(dot.contains("    Electromagnetism -> ElectromagneticMatrix;\n"));
+}
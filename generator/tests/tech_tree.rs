@@ -0,0 +1,73 @@
+use generator::{generator::AllDSPInfo, technologies::Technology};
+
+use strum::IntoEnumIterator;
+
+#[test]
+fn closures_match_immediate_prereqs() {
+    let info = AllDSPInfo::generate();
+    let tree = &info.tech_tree.0;
+    for tech in Technology::iter() {
+        let entry = &tree[tech];
+        assert!(
+            !entry.all_prereqs.contains(&tech),
+            "{:?} needs itself",
+            tech
+        );
+        for pre in entry.prereqs.iter() {
+            assert!(entry.all_prereqs.contains(pre));
+            assert!(tree[*pre].depth < entry.depth);
+        }
+        for pre in entry.all_prereqs.iter() {
+            assert!(tree[*pre].all_postreqs.contains(&tech));
+        }
+    }
+}
+
+#[test]
+fn everything_comes_from_the_start() {
+    let info = AllDSPInfo::generate();
+    let start = &info.tech_tree.0[Technology::DysonSphereProgram];
+    assert_eq!(start.depth, 0);
+    assert!(start.all_prereqs.is_empty());
+    assert_eq!(start.all_postreqs.len(), Technology::iter().count() - 1);
+}
+
+#[test]
+fn quantum_chip_requirements() {
+    let info = AllDSPInfo::generate();
+    let needs = &info.tech_tree.0[Technology::QuantumChip].all_prereqs;
+    for tech in [
+        Technology::DysonSphereProgram,
+        Technology::Electromagnetism,
+        Technology::Processor,
+        Technology::InformationMatrix,
+        Technology::CasimirCrystal,
+        Technology::WaveFunctionInterference,
+    ]
+    .iter()
+    {
+        assert!(needs.contains(tech), "QuantumChip should need {:?}", tech);
+    }
+    assert!(!needs.contains(&Technology::GravityMatrix));
+    assert!(!needs.contains(&Technology::QuantumChip));
+}
+
+#[test]
+fn steps_to_completion() {
+    let info = AllDSPInfo::generate();
+    let tree = &info.tech_tree.0;
+    assert_eq!(
+        tree[Technology::MissionCompleted].steps_to_completion,
+        Some(0)
+    );
+    assert_eq!(
+        tree[Technology::UniverseMatrix].steps_to_completion,
+        Some(1)
+    );
+    assert_eq!(
+        tree[Technology::DysonSphereProgram].steps_to_completion,
+        Some(tree[Technology::MissionCompleted].depth)
+    );
+    // The artificial star is a dead end
+    assert_eq!(tree[Technology::ArtificialStar].steps_to_completion, None);
+}
@@ -0,0 +1,53 @@
+use generator::{
+    items::Item,
+    mining::MiningModel,
+    upgrades::{Upgrade, UpgradeLevels},
+};
+
+use strum::IntoEnumIterator;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn costs() {
+    for upgrade in Upgrade::iter() {
+        assert!(upgrade.cost(0).is_none());
+        if let Some(max) = upgrade.max_level() {
+            assert!(upgrade.cost(max).is_some(), "{:?} {}", upgrade, max);
+            assert!(upgrade.cost(max + 1).is_none(), "{:?} {}", upgrade, max);
+        }
+    }
+
+    let first = Upgrade::MassConstruction.cost(1).unwrap();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first[1].item, Item::EnergyMatrix);
+    assert_eq!(first[1].count, 200.0);
+
+    // Past the table, it keeps needing every matrix and keeps going up
+    let later = Upgrade::VeinUtilization.cost(6).unwrap();
+    assert_eq!(later.len(), 6);
+    assert_eq!(later[5].item, Item::UniverseMatrix);
+    assert_eq!(later[5].count, 1800.0);
+}
+
+#[test]
+fn levels() {
+    let mut levels = UpgradeLevels::default();
+    assert!(levels.set(Upgrade::LogisticsCarrierCapacity, 9).is_err());
+    assert_eq!(levels.get(Upgrade::LogisticsCarrierCapacity), 0);
+    levels.set(Upgrade::LogisticsCarrierCapacity, 2).unwrap();
+    levels.set(Upgrade::LogisticsCarrierEngine, 3).unwrap();
+    levels.set(Upgrade::VeinUtilization, 25).unwrap();
+
+    let vessel = levels.vessel();
+    assert!(close(vessel.capacity, 400.0));
+    assert!(close(vessel.speed, 520.0));
+    assert!(close(levels.drone().capacity, 35.0));
+
+    let mut mining = MiningModel::default();
+    levels.apply_to_mining(&mut mining);
+    assert!(close(mining.speed, 3.5));
+    assert!(close(levels.research_speed(), 1.0));
+}
@@ -163,11 +163,11 @@ function makeItem(item: Item, type: keyof HTMLElementTagNameMap = "p", small = f
     elm.classList.add('dsp-item');
 
     if (OPTIONS.displayUsageLinks)
-        elm.innerHTML = `<img class="item-thumbnail" src="img/${item}.png" alt="${item}"></img> ${TRANSLATIONS.items[item]}
+        elm.innerHTML = `<img class="item-thumbnail" src="img/${item}.png" alt="${item}"></img> ${INFO.names.items[item]}
             [<a href="#?production=${item}">${small ? TRANSLATIONS.other.produceSmall : TRANSLATIONS.other.produce}</a>]
             [<a href="#?consumption=${item}">${small ? TRANSLATIONS.other.consumeSmall : TRANSLATIONS.other.consume}</a>]`;
     else {
-        elm.innerHTML = `<img class="item-thumbnail" src="img/${item}.png" alt="${item}"></img> ${TRANSLATIONS.items[item]}`;
+        elm.innerHTML = `<img class="item-thumbnail" src="img/${item}.png" alt="${item}"></img> ${INFO.names.items[item]}`;
         addHandlersItem(elm, item);
     }
 
@@ -182,11 +182,11 @@ function makeItemstack(stack: ItemStack, type: keyof HTMLElementTagNameMap = "p"
     elm.classList.add('dsp-itemstack');
 
     if (OPTIONS.displayUsageLinks)
-        elm.innerHTML = `${stack.count}x <img class="item-thumbnail" src="img/${stack.item}.png" alt="${stack.item}"></img> ${TRANSLATIONS.items[stack.item]}
+        elm.innerHTML = `${stack.count}x <img class="item-thumbnail" src="img/${stack.item}.png" alt="${stack.item}"></img> ${INFO.names.items[stack.item]}
         [<a href="#?action=production&item=${stack.item}">${small ? TRANSLATIONS.other.produceSmall : TRANSLATIONS.other.produce}</a>]
         [<a href="#?action=consumption&item=${stack.item}">${small ? TRANSLATIONS.other.consumeSmall : TRANSLATIONS.other.consume}</a>]`;
     else {
-        elm.innerHTML = `${stack.count}x <img class="item-thumbnail" src="img/${stack.item}.png" alt="${stack.item}"></img> ${TRANSLATIONS.items[stack.item]}`;
+        elm.innerHTML = `${stack.count}x <img class="item-thumbnail" src="img/${stack.item}.png" alt="${stack.item}"></img> ${INFO.names.items[stack.item]}`;
         addHandlersItem(elm, stack.item);
     }
 